@@ -0,0 +1,33 @@
+// Ballots are ordered by epoch, then number, then the id of the replica that
+// picked them, so two replicas can never hold the same ballot.
+
+use crate::epaxos::Ballot;
+use std::cmp::Ordering;
+
+impl PartialOrd for Ballot {
+    fn partial_cmp(&self, other: &Ballot) -> Option<Ordering> {
+        Some(
+            (self.get_epoch(), self.get_number(), self.get_replica_id()).cmp(&(
+                other.get_epoch(),
+                other.get_number(),
+                other.get_replica_id(),
+            )),
+        )
+    }
+}
+
+// The ballot a replica uses when it is the first to lead an instance
+pub fn initial_ballot(replica_id: i32) -> Ballot {
+    let mut ballot = Ballot::new();
+    ballot.set_replica_id(replica_id);
+    ballot
+}
+
+// A ballot for replica_id that is higher than every ballot up to current
+pub fn next_ballot(current: &Ballot, replica_id: i32) -> Ballot {
+    let mut ballot = Ballot::new();
+    ballot.set_epoch(current.get_epoch());
+    ballot.set_number(current.get_number() + 1);
+    ballot.set_replica_id(replica_id);
+    ballot
+}
@@ -0,0 +1,182 @@
+// Cluster configuration, loaded from a TOML file such as cluster.toml:
+//
+//     data_dir = "data"
+//     recovery_timeout_ms = 5000
+//     rpc_timeout_ms = 1000
+//     rpc_retries = 2
+//     max_in_flight = 64
+//     execution_interval_ms = 100
+//     thrifty = false
+//     latency_probe_interval_ms = 1000
+//
+//     [[replicas]]
+//     id = 0
+//     host = "127.0.0.1"
+//     port = 10000
+//
+// Replica ids must be 0..n, since they index the instance spaces.
+
+use serde::Deserialize;
+use std::{collections::HashSet, fmt, fs, io, path::PathBuf, time::Duration};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReplicaConfig {
+    pub id: i32,
+    pub host: String,
+    pub port: u16,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Config {
+    pub replicas: Vec<ReplicaConfig>,
+    pub data_dir: PathBuf,
+    #[serde(default = "default_recovery_timeout_ms")]
+    pub recovery_timeout_ms: u64,
+    // Deadline for a single RPC to a peer, and how often it is retried
+    #[serde(default = "default_rpc_timeout_ms")]
+    pub rpc_timeout_ms: u64,
+    #[serde(default = "default_rpc_retries")]
+    pub rpc_retries: u32,
+    // How many client requests a replica drives at the same time
+    #[serde(default = "default_max_in_flight")]
+    pub max_in_flight: usize,
+    // How often committed instances waiting on their dependencies are retried
+    #[serde(default = "default_execution_interval_ms")]
+    pub execution_interval_ms: u64,
+    // Send PreAccepts to a fast quorum only, instead of every replica
+    #[serde(default)]
+    pub thrifty: bool,
+    // How often round trip times to the other replicas are measured
+    #[serde(default = "default_latency_probe_interval_ms")]
+    pub latency_probe_interval_ms: u64,
+}
+
+fn default_recovery_timeout_ms() -> u64 {
+    5000
+}
+
+fn default_rpc_timeout_ms() -> u64 {
+    1000
+}
+
+fn default_rpc_retries() -> u32 {
+    2
+}
+
+fn default_max_in_flight() -> usize {
+    64
+}
+
+fn default_execution_interval_ms() -> u64 {
+    100
+}
+
+fn default_latency_probe_interval_ms() -> u64 {
+    1000
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+    Invalid(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Io(path, e) => write!(f, "cannot read {}: {}", path.display(), e),
+            ConfigError::Parse(path, e) => write!(f, "cannot parse {}: {}", path.display(), e),
+            ConfigError::Invalid(reason) => write!(f, "invalid configuration: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    pub fn load<P: Into<PathBuf>>(path: P) -> Result<Config, ConfigError> {
+        let path = path.into();
+        let contents = fs::read_to_string(&path).map_err(|e| ConfigError::Io(path.clone(), e))?;
+        let config: Config =
+            toml::from_str(&contents).map_err(|e| ConfigError::Parse(path.clone(), e))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let invalid = |reason: String| Err(ConfigError::Invalid(reason));
+        let replicas_num = self.replicas.len();
+        if replicas_num.is_multiple_of(2) {
+            return invalid(format!(
+                "EPaxos needs an odd number of replicas, got {}",
+                replicas_num
+            ));
+        }
+        let mut ids = HashSet::new();
+        let mut addrs = HashSet::new();
+        for replica in &self.replicas {
+            if replica.id < 0 || replica.id as usize >= replicas_num {
+                return invalid(format!(
+                    "replica id {} is out of range, ids must be 0..{}",
+                    replica.id, replicas_num
+                ));
+            }
+            if !ids.insert(replica.id) {
+                return invalid(format!("replica id {} is listed twice", replica.id));
+            }
+            if replica.host.is_empty() {
+                return invalid(format!("replica {} has an empty host", replica.id));
+            }
+            if replica.port == 0 {
+                return invalid(format!("replica {} has port 0", replica.id));
+            }
+            if !addrs.insert((replica.host.clone(), replica.port)) {
+                return invalid(format!(
+                    "replica {} reuses the address {}:{}",
+                    replica.id, replica.host, replica.port
+                ));
+            }
+        }
+        if self.recovery_timeout_ms == 0 {
+            return invalid("recovery_timeout_ms must be positive".to_owned());
+        }
+        if self.rpc_timeout_ms == 0 {
+            return invalid("rpc_timeout_ms must be positive".to_owned());
+        }
+        if self.max_in_flight == 0 {
+            return invalid("max_in_flight must be positive".to_owned());
+        }
+        if self.execution_interval_ms == 0 {
+            return invalid("execution_interval_ms must be positive".to_owned());
+        }
+        if self.latency_probe_interval_ms == 0 {
+            return invalid("latency_probe_interval_ms must be positive".to_owned());
+        }
+        Ok(())
+    }
+
+    pub fn replicas_num(&self) -> u16 {
+        self.replicas.len() as u16
+    }
+
+    pub fn replica(&self, id: i32) -> Option<&ReplicaConfig> {
+        self.replicas.iter().find(|replica| replica.id == id)
+    }
+
+    pub fn recovery_timeout(&self) -> Duration {
+        Duration::from_millis(self.recovery_timeout_ms)
+    }
+
+    pub fn rpc_timeout(&self) -> Duration {
+        Duration::from_millis(self.rpc_timeout_ms)
+    }
+
+    pub fn execution_interval(&self) -> Duration {
+        Duration::from_millis(self.execution_interval_ms)
+    }
+
+    pub fn latency_probe_interval(&self) -> Duration {
+        Duration::from_millis(self.latency_probe_interval_ms)
+    }
+}
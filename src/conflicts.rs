@@ -0,0 +1,209 @@
+// Per-key conflict index. For every key we remember the instances of each
+// replica that touched it, and the seq of the latest few, so finding the
+// interference of a new command costs O(replicas) instead of a scan over the
+// whole log. The index is fed with instances from every command leader.
+//
+// Reads and writes are tracked apart: a write interferes with every command on
+// its key, while a read only interferes with writes, so concurrent reads of
+// the same key can all commit on the fast path.
+
+use crate::deps::{self, instance_ref};
+use crate::epaxos::{Command, InstanceRef};
+use protobuf::RepeatedField;
+use std::{
+    cmp,
+    collections::{BTreeMap, BTreeSet, HashMap},
+};
+
+// How many of the latest instances of a replica we keep the seq of, per key and access
+const HISTORY: usize = 8;
+
+#[derive(Default)]
+struct ReplicaAccesses {
+    // Instance number to seq
+    recent: BTreeMap<i32, i32>,
+    // The older instances, without their seq. The executor only orders a command
+    // after the exact instance a dep names, so a dep has to name one that really
+    // touched the key.
+    forgotten: BTreeSet<i32>,
+    // The highest seq among the forgotten instances
+    forgotten_seq: Option<i32>,
+}
+
+impl ReplicaAccesses {
+    fn add(&mut self, instance_number: i32, seq: i32) {
+        self.recent.insert(instance_number, seq);
+        while self.recent.len() > HISTORY {
+            let oldest = *self.recent.keys().next().unwrap();
+            let seq = self.recent.remove(&oldest).unwrap();
+            self.forgotten.insert(oldest);
+            self.forgotten_seq = Some(cmp::max(self.forgotten_seq.unwrap_or(seq), seq));
+        }
+    }
+
+    // The newest instance, or the newest one below `below` if given
+    fn newest(&self, below: Option<i32>) -> Option<i32> {
+        match below {
+            Some(below) => self
+                .recent
+                .range(..below)
+                .next_back()
+                .map(|(instance, _)| *instance)
+                .or_else(|| self.forgotten.range(..below).next_back().cloned()),
+            None => self
+                .recent
+                .keys()
+                .next_back()
+                .or_else(|| self.forgotten.iter().next_back())
+                .cloned(),
+        }
+    }
+
+    // The highest seq, leaving out the instance `skip`
+    fn max_seq(&self, skip: Option<i32>) -> i32 {
+        self.recent
+            .iter()
+            .filter(|(instance, _)| Some(**instance) != skip)
+            .map(|(_, seq)| *seq)
+            .chain(self.forgotten_seq)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Default)]
+struct Accesses {
+    replicas: HashMap<i32, ReplicaAccesses>,
+}
+
+#[derive(Default)]
+struct KeyConflicts {
+    reads: Accesses,
+    writes: Accesses,
+}
+
+#[derive(Default)]
+pub struct Conflicts {
+    keys: HashMap<String, KeyConflicts>,
+}
+
+impl Conflicts {
+    pub fn new() -> Conflicts {
+        Default::default()
+    }
+
+    // Record that replica_id.instance_number reads or writes key with the given seq
+    pub fn update(
+        &mut self,
+        key: &str,
+        write: bool,
+        replica_id: i32,
+        instance_number: i32,
+        seq: i32,
+    ) {
+        let conflicts = self.keys.entry(key.to_owned()).or_default();
+        let accesses = if write {
+            &mut conflicts.writes
+        } else {
+            &mut conflicts.reads
+        };
+        accesses
+            .replicas
+            .entry(replica_id)
+            .or_default()
+            .add(instance_number, seq);
+    }
+
+    // Record the instance of cmd with its current seq
+    pub fn add(&mut self, cmd: &Command) {
+        let key = if cmd.has_write_req() {
+            cmd.get_write_req().get_key()
+        } else if cmd.has_read_req() {
+            cmd.get_read_req().get_key()
+        } else {
+            // no-ops do not interfere with anything
+            return;
+        };
+        self.update(
+            key,
+            cmd.has_write_req(),
+            cmd.get_replica_id(),
+            cmd.get_instance_number(),
+            cmd.get_seq(),
+        );
+    }
+
+    // The deps and max seq a read or write of key gets from the instances we
+    // know of. replica_id.instance_number is the command's own instance: it
+    // depends on the instances of its own replica below it, and its own seq
+    // does not count, so recovering or re-proposing it gives the same result.
+    pub fn find(
+        &self,
+        key: &str,
+        write: bool,
+        replica_id: i32,
+        instance_number: i32,
+    ) -> (RepeatedField<InstanceRef>, i32) {
+        let mut deps = RepeatedField::new();
+        let mut max_seq = 0;
+        let conflicts = match self.keys.get(key) {
+            Some(conflicts) => conflicts,
+            None => return (deps, max_seq),
+        };
+        let mut interfering = vec![&conflicts.writes];
+        if write {
+            interfering.push(&conflicts.reads);
+        }
+        for accesses in interfering {
+            for (replica, replica_accesses) in accesses.replicas.iter() {
+                let own = if *replica == replica_id {
+                    Some(instance_number)
+                } else {
+                    None
+                };
+                if let Some(instance) = replica_accesses.newest(own) {
+                    deps::add(&mut deps, &instance_ref(*replica, instance));
+                }
+                max_seq = cmp::max(max_seq, replica_accesses.max_seq(own));
+            }
+        }
+        (deps, max_seq)
+    }
+}
+
+// Whether a and b interfere: they touch the same key and one of them writes it.
+// No-ops interfere with nothing.
+pub fn interfere(a: &Command, b: &Command) -> bool {
+    let key = |cmd: &Command| {
+        if cmd.has_write_req() {
+            Some(cmd.get_write_req().get_key().to_owned())
+        } else if cmd.has_read_req() {
+            Some(cmd.get_read_req().get_key().to_owned())
+        } else {
+            None
+        }
+    };
+    match (key(a), key(b)) {
+        (Some(a_key), Some(b_key)) => a_key == b_key && (a.has_write_req() || b.has_write_req()),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Recovering an old instance of a replica with many newer ones on the key
+    // still depends on the instance of that replica right before it on the key
+    #[test]
+    fn deps_name_an_instance_that_touched_the_key() {
+        let mut conflicts = Conflicts::new();
+        conflicts.update("x", true, 0, 0, 1);
+        conflicts.update("y", true, 0, 1, 1);
+        for instance_number in 2..2 + HISTORY as i32 + 1 {
+            conflicts.update("x", true, 0, instance_number, instance_number);
+        }
+        let (deps, _) = conflicts.find("x", true, 0, 2);
+        assert_eq!(deps.into_vec(), vec![instance_ref(0, 0)]);
+    }
+}
@@ -0,0 +1,46 @@
+// Dependencies are references to instances rather than copies of commands.
+// We keep at most one reference per replica, to the highest interfering
+// instance in that replica's instance space, sorted by replica id so that two
+// sets of deps can be compared directly.
+
+use crate::epaxos::InstanceRef;
+use protobuf::RepeatedField;
+
+pub fn instance_ref(replica_id: i32, instance_number: i32) -> InstanceRef {
+    let mut dep = InstanceRef::new();
+    dep.set_replica_id(replica_id);
+    dep.set_instance_number(instance_number);
+    dep
+}
+
+// Adds dep unless we already depend on a later instance of the same replica
+pub fn add(deps: &mut RepeatedField<InstanceRef>, dep: &InstanceRef) {
+    match deps
+        .iter_mut()
+        .find(|known| known.get_replica_id() == dep.get_replica_id())
+    {
+        Some(known) => {
+            if dep.get_instance_number() > known.get_instance_number() {
+                known.set_instance_number(dep.get_instance_number());
+            }
+        }
+        None => {
+            deps.push(dep.clone());
+            deps.sort_by_key(|dep| dep.get_replica_id());
+        }
+    }
+}
+
+// Whether deps include replica_id.instance_number, directly or through a later
+// instance of the same replica
+pub fn covers(deps: &[InstanceRef], replica_id: i32, instance_number: i32) -> bool {
+    deps.iter().any(|dep| {
+        dep.get_replica_id() == replica_id && dep.get_instance_number() >= instance_number
+    })
+}
+
+pub fn union(deps: &mut RepeatedField<InstanceRef>, other: &[InstanceRef]) {
+    for dep in other {
+        add(deps, dep);
+    }
+}
@@ -565,6 +565,7 @@ impl ::protobuf::reflect::ProtobufValue for ReadRequest {
 pub struct ReadResponse {
     // message fields
     pub value: i32,
+    pub found: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -595,6 +596,21 @@ impl ReadResponse {
     pub fn set_value(&mut self, v: i32) {
         self.value = v;
     }
+
+    // bool found = 2;
+
+
+    pub fn get_found(&self) -> bool {
+        self.found
+    }
+    pub fn clear_found(&mut self) {
+        self.found = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_found(&mut self, v: bool) {
+        self.found = v;
+    }
 }
 
 impl ::protobuf::Message for ReadResponse {
@@ -613,6 +629,13 @@ impl ::protobuf::Message for ReadResponse {
                     let tmp = is.read_int32()?;
                     self.value = tmp;
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.found = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -628,6 +651,9 @@ impl ::protobuf::Message for ReadResponse {
         if self.value != 0 {
             my_size += ::protobuf::rt::value_size(1, self.value, ::protobuf::wire_format::WireTypeVarint);
         }
+        if self.found != false {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -637,6 +663,9 @@ impl ::protobuf::Message for ReadResponse {
         if self.value != 0 {
             os.write_int32(1, self.value)?;
         }
+        if self.found != false {
+            os.write_bool(2, self.found)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -684,6 +713,11 @@ impl ::protobuf::Message for ReadResponse {
                     |m: &ReadResponse| { &m.value },
                     |m: &mut ReadResponse| { &mut m.value },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "found",
+                    |m: &ReadResponse| { &m.found },
+                    |m: &mut ReadResponse| { &mut m.found },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<ReadResponse>(
                     "ReadResponse",
                     fields,
@@ -707,6 +741,7 @@ impl ::protobuf::Message for ReadResponse {
 impl ::protobuf::Clear for ReadResponse {
     fn clear(&mut self) {
         self.value = 0;
+        self.found = false;
         self.unknown_fields.clear();
     }
 }
@@ -724,206 +759,75 @@ impl ::protobuf::reflect::ProtobufValue for ReadResponse {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Command {
+pub struct Ballot {
     // message fields
-    pub seq: i32,
-    pub deps: ::protobuf::RepeatedField<Command>,
-    pub state: State,
-    // message oneof groups
-    pub ClientRequest: ::std::option::Option<Command_oneof_ClientRequest>,
+    pub epoch: i32,
+    pub number: i32,
+    pub replica_id: i32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Command {
-    fn default() -> &'a Command {
-        <Command as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Ballot {
+    fn default() -> &'a Ballot {
+        <Ballot as ::protobuf::Message>::default_instance()
     }
 }
 
-#[derive(Clone,PartialEq,Debug)]
-pub enum Command_oneof_ClientRequest {
-    write_req(WriteRequest),
-    read_req(ReadRequest),
-}
-
-impl Command {
-    pub fn new() -> Command {
+impl Ballot {
+    pub fn new() -> Ballot {
         ::std::default::Default::default()
     }
 
-    // .epaxos.WriteRequest write_req = 1;
-
-
-    pub fn get_write_req(&self) -> &WriteRequest {
-        match self.ClientRequest {
-            ::std::option::Option::Some(Command_oneof_ClientRequest::write_req(ref v)) => v,
-            _ => WriteRequest::default_instance(),
-        }
-    }
-    pub fn clear_write_req(&mut self) {
-        self.ClientRequest = ::std::option::Option::None;
-    }
-
-    pub fn has_write_req(&self) -> bool {
-        match self.ClientRequest {
-            ::std::option::Option::Some(Command_oneof_ClientRequest::write_req(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_write_req(&mut self, v: WriteRequest) {
-        self.ClientRequest = ::std::option::Option::Some(Command_oneof_ClientRequest::write_req(v))
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_write_req(&mut self) -> &mut WriteRequest {
-        if let ::std::option::Option::Some(Command_oneof_ClientRequest::write_req(_)) = self.ClientRequest {
-        } else {
-            self.ClientRequest = ::std::option::Option::Some(Command_oneof_ClientRequest::write_req(WriteRequest::new()));
-        }
-        match self.ClientRequest {
-            ::std::option::Option::Some(Command_oneof_ClientRequest::write_req(ref mut v)) => v,
-            _ => panic!(),
-        }
-    }
-
-    // Take field
-    pub fn take_write_req(&mut self) -> WriteRequest {
-        if self.has_write_req() {
-            match self.ClientRequest.take() {
-                ::std::option::Option::Some(Command_oneof_ClientRequest::write_req(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            WriteRequest::new()
-        }
-    }
-
-    // .epaxos.ReadRequest read_req = 2;
-
-
-    pub fn get_read_req(&self) -> &ReadRequest {
-        match self.ClientRequest {
-            ::std::option::Option::Some(Command_oneof_ClientRequest::read_req(ref v)) => v,
-            _ => ReadRequest::default_instance(),
-        }
-    }
-    pub fn clear_read_req(&mut self) {
-        self.ClientRequest = ::std::option::Option::None;
-    }
-
-    pub fn has_read_req(&self) -> bool {
-        match self.ClientRequest {
-            ::std::option::Option::Some(Command_oneof_ClientRequest::read_req(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_read_req(&mut self, v: ReadRequest) {
-        self.ClientRequest = ::std::option::Option::Some(Command_oneof_ClientRequest::read_req(v))
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_read_req(&mut self) -> &mut ReadRequest {
-        if let ::std::option::Option::Some(Command_oneof_ClientRequest::read_req(_)) = self.ClientRequest {
-        } else {
-            self.ClientRequest = ::std::option::Option::Some(Command_oneof_ClientRequest::read_req(ReadRequest::new()));
-        }
-        match self.ClientRequest {
-            ::std::option::Option::Some(Command_oneof_ClientRequest::read_req(ref mut v)) => v,
-            _ => panic!(),
-        }
-    }
-
-    // Take field
-    pub fn take_read_req(&mut self) -> ReadRequest {
-        if self.has_read_req() {
-            match self.ClientRequest.take() {
-                ::std::option::Option::Some(Command_oneof_ClientRequest::read_req(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            ReadRequest::new()
-        }
-    }
-
-    // int32 seq = 3;
+    // int32 epoch = 1;
 
 
-    pub fn get_seq(&self) -> i32 {
-        self.seq
+    pub fn get_epoch(&self) -> i32 {
+        self.epoch
     }
-    pub fn clear_seq(&mut self) {
-        self.seq = 0;
+    pub fn clear_epoch(&mut self) {
+        self.epoch = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_seq(&mut self, v: i32) {
-        self.seq = v;
+    pub fn set_epoch(&mut self, v: i32) {
+        self.epoch = v;
     }
 
-    // repeated .epaxos.Command deps = 4;
+    // int32 number = 2;
 
 
-    pub fn get_deps(&self) -> &[Command] {
-        &self.deps
+    pub fn get_number(&self) -> i32 {
+        self.number
     }
-    pub fn clear_deps(&mut self) {
-        self.deps.clear();
+    pub fn clear_number(&mut self) {
+        self.number = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_deps(&mut self, v: ::protobuf::RepeatedField<Command>) {
-        self.deps = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_deps(&mut self) -> &mut ::protobuf::RepeatedField<Command> {
-        &mut self.deps
-    }
-
-    // Take field
-    pub fn take_deps(&mut self) -> ::protobuf::RepeatedField<Command> {
-        ::std::mem::replace(&mut self.deps, ::protobuf::RepeatedField::new())
+    pub fn set_number(&mut self, v: i32) {
+        self.number = v;
     }
 
-    // .epaxos.State state = 5;
+    // int32 replica_id = 3;
 
 
-    pub fn get_state(&self) -> State {
-        self.state
+    pub fn get_replica_id(&self) -> i32 {
+        self.replica_id
     }
-    pub fn clear_state(&mut self) {
-        self.state = State::PRE_ACCEPT;
+    pub fn clear_replica_id(&mut self) {
+        self.replica_id = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_state(&mut self, v: State) {
-        self.state = v;
+    pub fn set_replica_id(&mut self, v: i32) {
+        self.replica_id = v;
     }
 }
 
-impl ::protobuf::Message for Command {
+impl ::protobuf::Message for Ballot {
     fn is_initialized(&self) -> bool {
-        if let Some(Command_oneof_ClientRequest::write_req(ref v)) = self.ClientRequest {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Command_oneof_ClientRequest::read_req(ref v)) = self.ClientRequest {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        for v in &self.deps {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -932,29 +836,25 @@ impl ::protobuf::Message for Command {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.ClientRequest = ::std::option::Option::Some(Command_oneof_ClientRequest::write_req(is.read_message()?));
+                    let tmp = is.read_int32()?;
+                    self.epoch = tmp;
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.ClientRequest = ::std::option::Option::Some(Command_oneof_ClientRequest::read_req(is.read_message()?));
+                    let tmp = is.read_int32()?;
+                    self.number = tmp;
                 },
                 3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_int32()?;
-                    self.seq = tmp;
-                },
-                4 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.deps)?;
-                },
-                5 => {
-                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.state, 5, &mut self.unknown_fields)?
+                    self.replica_id = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -968,27 +868,14 @@ impl ::protobuf::Message for Command {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.seq != 0 {
-            my_size += ::protobuf::rt::value_size(3, self.seq, ::protobuf::wire_format::WireTypeVarint);
+        if self.epoch != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.epoch, ::protobuf::wire_format::WireTypeVarint);
         }
-        for value in &self.deps {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        if self.state != State::PRE_ACCEPT {
-            my_size += ::protobuf::rt::enum_size(5, self.state);
+        if self.number != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.number, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let ::std::option::Option::Some(ref v) = self.ClientRequest {
-            match v {
-                &Command_oneof_ClientRequest::write_req(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Command_oneof_ClientRequest::read_req(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-            };
+        if self.replica_id != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.replica_id, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -996,30 +883,14 @@ impl ::protobuf::Message for Command {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if self.seq != 0 {
-            os.write_int32(3, self.seq)?;
+        if self.epoch != 0 {
+            os.write_int32(1, self.epoch)?;
         }
-        for v in &self.deps {
-            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        if self.state != State::PRE_ACCEPT {
-            os.write_enum(5, self.state.value())?;
+        if self.number != 0 {
+            os.write_int32(2, self.number)?;
         }
-        if let ::std::option::Option::Some(ref v) = self.ClientRequest {
-            match v {
-                &Command_oneof_ClientRequest::write_req(ref v) => {
-                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Command_oneof_ClientRequest::read_req(ref v) => {
-                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-            };
+        if self.replica_id != 0 {
+            os.write_int32(3, self.replica_id)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1051,8 +922,8 @@ impl ::protobuf::Message for Command {
         Self::descriptor_static()
     }
 
-    fn new() -> Command {
-        Command::new()
+    fn new() -> Ballot {
+        Ballot::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1063,33 +934,23 @@ impl ::protobuf::Message for Command {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WriteRequest>(
-                    "write_req",
-                    Command::has_write_req,
-                    Command::get_write_req,
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "epoch",
+                    |m: &Ballot| { &m.epoch },
+                    |m: &mut Ballot| { &mut m.epoch },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ReadRequest>(
-                    "read_req",
-                    Command::has_read_req,
-                    Command::get_read_req,
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "number",
+                    |m: &Ballot| { &m.number },
+                    |m: &mut Ballot| { &mut m.number },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "seq",
-                    |m: &Command| { &m.seq },
-                    |m: &mut Command| { &mut m.seq },
+                    "replica_id",
+                    |m: &Ballot| { &m.replica_id },
+                    |m: &mut Ballot| { &mut m.replica_id },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Command>>(
-                    "deps",
-                    |m: &Command| { &m.deps },
-                    |m: &mut Command| { &mut m.deps },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<State>>(
-                    "state",
-                    |m: &Command| { &m.state },
-                    |m: &mut Command| { &mut m.state },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Command>(
-                    "Command",
+                ::protobuf::reflect::MessageDescriptor::new::<Ballot>(
+                    "Ballot",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1097,68 +958,56 @@ impl ::protobuf::Message for Command {
         }
     }
 
-    fn default_instance() -> &'static Command {
-        static mut instance: ::protobuf::lazy::Lazy<Command> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static Ballot {
+        static mut instance: ::protobuf::lazy::Lazy<Ballot> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Command,
+            ptr: 0 as *const Ballot,
         };
         unsafe {
-            instance.get(Command::new)
+            instance.get(Ballot::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Command {
+impl ::protobuf::Clear for Ballot {
     fn clear(&mut self) {
-        self.ClientRequest = ::std::option::Option::None;
-        self.ClientRequest = ::std::option::Option::None;
-        self.seq = 0;
-        self.deps.clear();
-        self.state = State::PRE_ACCEPT;
+        self.epoch = 0;
+        self.number = 0;
+        self.replica_id = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Command {
+impl ::std::fmt::Debug for Ballot {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Command {
+impl ::protobuf::reflect::ProtobufValue for Ballot {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct PreAccept {
+pub struct InstanceRef {
     // message fields
     pub replica_id: i32,
-    pub seq: i32,
-    pub deps: ::protobuf::RepeatedField<Command>,
     pub instance_number: i32,
-    // message oneof groups
-    pub ClientRequest: ::std::option::Option<PreAccept_oneof_ClientRequest>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a PreAccept {
-    fn default() -> &'a PreAccept {
-        <PreAccept as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a InstanceRef {
+    fn default() -> &'a InstanceRef {
+        <InstanceRef as ::protobuf::Message>::default_instance()
     }
 }
 
-#[derive(Clone,PartialEq,Debug)]
-pub enum PreAccept_oneof_ClientRequest {
-    write_req(WriteRequest),
-    read_req(ReadRequest),
-}
-
-impl PreAccept {
-    pub fn new() -> PreAccept {
+impl InstanceRef {
+    pub fn new() -> InstanceRef {
         ::std::default::Default::default()
     }
 
@@ -1177,12 +1026,207 @@ impl PreAccept {
         self.replica_id = v;
     }
 
-    // .epaxos.WriteRequest write_req = 2;
+    // int32 instance_number = 2;
+
+
+    pub fn get_instance_number(&self) -> i32 {
+        self.instance_number
+    }
+    pub fn clear_instance_number(&mut self) {
+        self.instance_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_instance_number(&mut self, v: i32) {
+        self.instance_number = v;
+    }
+}
+
+impl ::protobuf::Message for InstanceRef {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.replica_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.instance_number = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.replica_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.replica_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.instance_number != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.instance_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.replica_id != 0 {
+            os.write_int32(1, self.replica_id)?;
+        }
+        if self.instance_number != 0 {
+            os.write_int32(2, self.instance_number)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> InstanceRef {
+        InstanceRef::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "replica_id",
+                    |m: &InstanceRef| { &m.replica_id },
+                    |m: &mut InstanceRef| { &mut m.replica_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "instance_number",
+                    |m: &InstanceRef| { &m.instance_number },
+                    |m: &mut InstanceRef| { &mut m.instance_number },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<InstanceRef>(
+                    "InstanceRef",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static InstanceRef {
+        static mut instance: ::protobuf::lazy::Lazy<InstanceRef> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const InstanceRef,
+        };
+        unsafe {
+            instance.get(InstanceRef::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for InstanceRef {
+    fn clear(&mut self) {
+        self.replica_id = 0;
+        self.instance_number = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for InstanceRef {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InstanceRef {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Command {
+    // message fields
+    pub seq: i32,
+    pub deps: ::protobuf::RepeatedField<InstanceRef>,
+    pub state: State,
+    pub replica_id: i32,
+    pub instance_number: i32,
+    pub ballot: ::protobuf::SingularPtrField<Ballot>,
+    pub accepted_ballot: ::protobuf::SingularPtrField<Ballot>,
+    // message oneof groups
+    pub ClientRequest: ::std::option::Option<Command_oneof_ClientRequest>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Command {
+    fn default() -> &'a Command {
+        <Command as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum Command_oneof_ClientRequest {
+    write_req(WriteRequest),
+    read_req(ReadRequest),
+}
+
+impl Command {
+    pub fn new() -> Command {
+        ::std::default::Default::default()
+    }
+
+    // .epaxos.WriteRequest write_req = 1;
 
 
     pub fn get_write_req(&self) -> &WriteRequest {
         match self.ClientRequest {
-            ::std::option::Option::Some(PreAccept_oneof_ClientRequest::write_req(ref v)) => v,
+            ::std::option::Option::Some(Command_oneof_ClientRequest::write_req(ref v)) => v,
             _ => WriteRequest::default_instance(),
         }
     }
@@ -1192,24 +1236,24 @@ impl PreAccept {
 
     pub fn has_write_req(&self) -> bool {
         match self.ClientRequest {
-            ::std::option::Option::Some(PreAccept_oneof_ClientRequest::write_req(..)) => true,
+            ::std::option::Option::Some(Command_oneof_ClientRequest::write_req(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
     pub fn set_write_req(&mut self, v: WriteRequest) {
-        self.ClientRequest = ::std::option::Option::Some(PreAccept_oneof_ClientRequest::write_req(v))
+        self.ClientRequest = ::std::option::Option::Some(Command_oneof_ClientRequest::write_req(v))
     }
 
     // Mutable pointer to the field.
     pub fn mut_write_req(&mut self) -> &mut WriteRequest {
-        if let ::std::option::Option::Some(PreAccept_oneof_ClientRequest::write_req(_)) = self.ClientRequest {
+        if let ::std::option::Option::Some(Command_oneof_ClientRequest::write_req(_)) = self.ClientRequest {
         } else {
-            self.ClientRequest = ::std::option::Option::Some(PreAccept_oneof_ClientRequest::write_req(WriteRequest::new()));
+            self.ClientRequest = ::std::option::Option::Some(Command_oneof_ClientRequest::write_req(WriteRequest::new()));
         }
         match self.ClientRequest {
-            ::std::option::Option::Some(PreAccept_oneof_ClientRequest::write_req(ref mut v)) => v,
+            ::std::option::Option::Some(Command_oneof_ClientRequest::write_req(ref mut v)) => v,
             _ => panic!(),
         }
     }
@@ -1218,7 +1262,7 @@ impl PreAccept {
     pub fn take_write_req(&mut self) -> WriteRequest {
         if self.has_write_req() {
             match self.ClientRequest.take() {
-                ::std::option::Option::Some(PreAccept_oneof_ClientRequest::write_req(v)) => v,
+                ::std::option::Option::Some(Command_oneof_ClientRequest::write_req(v)) => v,
                 _ => panic!(),
             }
         } else {
@@ -1226,12 +1270,12 @@ impl PreAccept {
         }
     }
 
-    // .epaxos.ReadRequest read_req = 3;
+    // .epaxos.ReadRequest read_req = 2;
 
 
     pub fn get_read_req(&self) -> &ReadRequest {
         match self.ClientRequest {
-            ::std::option::Option::Some(PreAccept_oneof_ClientRequest::read_req(ref v)) => v,
+            ::std::option::Option::Some(Command_oneof_ClientRequest::read_req(ref v)) => v,
             _ => ReadRequest::default_instance(),
         }
     }
@@ -1241,24 +1285,24 @@ impl PreAccept {
 
     pub fn has_read_req(&self) -> bool {
         match self.ClientRequest {
-            ::std::option::Option::Some(PreAccept_oneof_ClientRequest::read_req(..)) => true,
+            ::std::option::Option::Some(Command_oneof_ClientRequest::read_req(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
     pub fn set_read_req(&mut self, v: ReadRequest) {
-        self.ClientRequest = ::std::option::Option::Some(PreAccept_oneof_ClientRequest::read_req(v))
+        self.ClientRequest = ::std::option::Option::Some(Command_oneof_ClientRequest::read_req(v))
     }
 
     // Mutable pointer to the field.
     pub fn mut_read_req(&mut self) -> &mut ReadRequest {
-        if let ::std::option::Option::Some(PreAccept_oneof_ClientRequest::read_req(_)) = self.ClientRequest {
+        if let ::std::option::Option::Some(Command_oneof_ClientRequest::read_req(_)) = self.ClientRequest {
         } else {
-            self.ClientRequest = ::std::option::Option::Some(PreAccept_oneof_ClientRequest::read_req(ReadRequest::new()));
+            self.ClientRequest = ::std::option::Option::Some(Command_oneof_ClientRequest::read_req(ReadRequest::new()));
         }
         match self.ClientRequest {
-            ::std::option::Option::Some(PreAccept_oneof_ClientRequest::read_req(ref mut v)) => v,
+            ::std::option::Option::Some(Command_oneof_ClientRequest::read_req(ref mut v)) => v,
             _ => panic!(),
         }
     }
@@ -1267,7 +1311,7 @@ impl PreAccept {
     pub fn take_read_req(&mut self) -> ReadRequest {
         if self.has_read_req() {
             match self.ClientRequest.take() {
-                ::std::option::Option::Some(PreAccept_oneof_ClientRequest::read_req(v)) => v,
+                ::std::option::Option::Some(Command_oneof_ClientRequest::read_req(v)) => v,
                 _ => panic!(),
             }
         } else {
@@ -1275,7 +1319,7 @@ impl PreAccept {
         }
     }
 
-    // int32 seq = 4;
+    // int32 seq = 3;
 
 
     pub fn get_seq(&self) -> i32 {
@@ -1290,10 +1334,10 @@ impl PreAccept {
         self.seq = v;
     }
 
-    // repeated .epaxos.Command deps = 5;
+    // repeated .epaxos.InstanceRef deps = 4;
 
 
-    pub fn get_deps(&self) -> &[Command] {
+    pub fn get_deps(&self) -> &[InstanceRef] {
         &self.deps
     }
     pub fn clear_deps(&mut self) {
@@ -1301,44 +1345,140 @@ impl PreAccept {
     }
 
     // Param is passed by value, moved
-    pub fn set_deps(&mut self, v: ::protobuf::RepeatedField<Command>) {
+    pub fn set_deps(&mut self, v: ::protobuf::RepeatedField<InstanceRef>) {
         self.deps = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_deps(&mut self) -> &mut ::protobuf::RepeatedField<Command> {
+    pub fn mut_deps(&mut self) -> &mut ::protobuf::RepeatedField<InstanceRef> {
         &mut self.deps
     }
 
     // Take field
-    pub fn take_deps(&mut self) -> ::protobuf::RepeatedField<Command> {
+    pub fn take_deps(&mut self) -> ::protobuf::RepeatedField<InstanceRef> {
         ::std::mem::replace(&mut self.deps, ::protobuf::RepeatedField::new())
     }
 
-    // int32 instance_number = 6;
+    // .epaxos.State state = 5;
 
 
-    pub fn get_instance_number(&self) -> i32 {
-        self.instance_number
+    pub fn get_state(&self) -> State {
+        self.state
     }
-    pub fn clear_instance_number(&mut self) {
-        self.instance_number = 0;
+    pub fn clear_state(&mut self) {
+        self.state = State::NONE;
     }
 
     // Param is passed by value, moved
-    pub fn set_instance_number(&mut self, v: i32) {
-        self.instance_number = v;
-    }
+    pub fn set_state(&mut self, v: State) {
+        self.state = v;
+    }
+
+    // int32 replica_id = 6;
+
+
+    pub fn get_replica_id(&self) -> i32 {
+        self.replica_id
+    }
+    pub fn clear_replica_id(&mut self) {
+        self.replica_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_replica_id(&mut self, v: i32) {
+        self.replica_id = v;
+    }
+
+    // int32 instance_number = 7;
+
+
+    pub fn get_instance_number(&self) -> i32 {
+        self.instance_number
+    }
+    pub fn clear_instance_number(&mut self) {
+        self.instance_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_instance_number(&mut self, v: i32) {
+        self.instance_number = v;
+    }
+
+    // .epaxos.Ballot ballot = 8;
+
+
+    pub fn get_ballot(&self) -> &Ballot {
+        self.ballot.as_ref().unwrap_or_else(|| Ballot::default_instance())
+    }
+    pub fn clear_ballot(&mut self) {
+        self.ballot.clear();
+    }
+
+    pub fn has_ballot(&self) -> bool {
+        self.ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ballot(&mut self, v: Ballot) {
+        self.ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ballot(&mut self) -> &mut Ballot {
+        if self.ballot.is_none() {
+            self.ballot.set_default();
+        }
+        self.ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ballot(&mut self) -> Ballot {
+        self.ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+
+    // .epaxos.Ballot accepted_ballot = 9;
+
+
+    pub fn get_accepted_ballot(&self) -> &Ballot {
+        self.accepted_ballot.as_ref().unwrap_or_else(|| Ballot::default_instance())
+    }
+    pub fn clear_accepted_ballot(&mut self) {
+        self.accepted_ballot.clear();
+    }
+
+    pub fn has_accepted_ballot(&self) -> bool {
+        self.accepted_ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accepted_ballot(&mut self, v: Ballot) {
+        self.accepted_ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_accepted_ballot(&mut self) -> &mut Ballot {
+        if self.accepted_ballot.is_none() {
+            self.accepted_ballot.set_default();
+        }
+        self.accepted_ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_accepted_ballot(&mut self) -> Ballot {
+        self.accepted_ballot.take().unwrap_or_else(|| Ballot::new())
+    }
 }
 
-impl ::protobuf::Message for PreAccept {
+impl ::protobuf::Message for Command {
     fn is_initialized(&self) -> bool {
-        if let Some(PreAccept_oneof_ClientRequest::write_req(ref v)) = self.ClientRequest {
+        if let Some(Command_oneof_ClientRequest::write_req(ref v)) = self.ClientRequest {
             if !v.is_initialized() {
                 return false;
             }
         }
-        if let Some(PreAccept_oneof_ClientRequest::read_req(ref v)) = self.ClientRequest {
+        if let Some(Command_oneof_ClientRequest::read_req(ref v)) = self.ClientRequest {
             if !v.is_initialized() {
                 return false;
             }
@@ -1348,6 +1488,16 @@ impl ::protobuf::Message for PreAccept {
                 return false;
             }
         };
+        for v in &self.ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.accepted_ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -1356,41 +1506,50 @@ impl ::protobuf::Message for PreAccept {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int32()?;
-                    self.replica_id = tmp;
+                    self.ClientRequest = ::std::option::Option::Some(Command_oneof_ClientRequest::write_req(is.read_message()?));
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.ClientRequest = ::std::option::Option::Some(PreAccept_oneof_ClientRequest::write_req(is.read_message()?));
+                    self.ClientRequest = ::std::option::Option::Some(Command_oneof_ClientRequest::read_req(is.read_message()?));
                 },
                 3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.ClientRequest = ::std::option::Option::Some(PreAccept_oneof_ClientRequest::read_req(is.read_message()?));
-                },
-                4 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_int32()?;
                     self.seq = tmp;
                 },
-                5 => {
+                4 => {
                     ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.deps)?;
                 },
+                5 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.state, 5, &mut self.unknown_fields)?
+                },
                 6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.replica_id = tmp;
+                },
+                7 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_int32()?;
                     self.instance_number = tmp;
                 },
+                8 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ballot)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.accepted_ballot)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1403,26 +1562,37 @@ impl ::protobuf::Message for PreAccept {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.replica_id != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.replica_id, ::protobuf::wire_format::WireTypeVarint);
-        }
         if self.seq != 0 {
-            my_size += ::protobuf::rt::value_size(4, self.seq, ::protobuf::wire_format::WireTypeVarint);
+            my_size += ::protobuf::rt::value_size(3, self.seq, ::protobuf::wire_format::WireTypeVarint);
         }
         for value in &self.deps {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        if self.state != State::NONE {
+            my_size += ::protobuf::rt::enum_size(5, self.state);
+        }
+        if self.replica_id != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.replica_id, ::protobuf::wire_format::WireTypeVarint);
+        }
         if self.instance_number != 0 {
-            my_size += ::protobuf::rt::value_size(6, self.instance_number, ::protobuf::wire_format::WireTypeVarint);
+            my_size += ::protobuf::rt::value_size(7, self.instance_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.accepted_ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
         if let ::std::option::Option::Some(ref v) = self.ClientRequest {
             match v {
-                &PreAccept_oneof_ClientRequest::write_req(ref v) => {
+                &Command_oneof_ClientRequest::write_req(ref v) => {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
-                &PreAccept_oneof_ClientRequest::read_req(ref v) => {
+                &Command_oneof_ClientRequest::read_req(ref v) => {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
@@ -1434,29 +1604,42 @@ impl ::protobuf::Message for PreAccept {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if self.replica_id != 0 {
-            os.write_int32(1, self.replica_id)?;
-        }
         if self.seq != 0 {
-            os.write_int32(4, self.seq)?;
+            os.write_int32(3, self.seq)?;
         }
         for v in &self.deps {
-            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
+        if self.state != State::NONE {
+            os.write_enum(5, self.state.value())?;
+        }
+        if self.replica_id != 0 {
+            os.write_int32(6, self.replica_id)?;
+        }
         if self.instance_number != 0 {
-            os.write_int32(6, self.instance_number)?;
+            os.write_int32(7, self.instance_number)?;
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.accepted_ballot.as_ref() {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
         if let ::std::option::Option::Some(ref v) = self.ClientRequest {
             match v {
-                &PreAccept_oneof_ClientRequest::write_req(ref v) => {
-                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                &Command_oneof_ClientRequest::write_req(ref v) => {
+                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
-                &PreAccept_oneof_ClientRequest::read_req(ref v) => {
-                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                &Command_oneof_ClientRequest::read_req(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
@@ -1492,8 +1675,8 @@ impl ::protobuf::Message for PreAccept {
         Self::descriptor_static()
     }
 
-    fn new() -> PreAccept {
-        PreAccept::new()
+    fn new() -> Command {
+        Command::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1504,38 +1687,53 @@ impl ::protobuf::Message for PreAccept {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "replica_id",
-                    |m: &PreAccept| { &m.replica_id },
-                    |m: &mut PreAccept| { &mut m.replica_id },
-                ));
                 fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WriteRequest>(
                     "write_req",
-                    PreAccept::has_write_req,
-                    PreAccept::get_write_req,
+                    Command::has_write_req,
+                    Command::get_write_req,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ReadRequest>(
                     "read_req",
-                    PreAccept::has_read_req,
-                    PreAccept::get_read_req,
+                    Command::has_read_req,
+                    Command::get_read_req,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
                     "seq",
-                    |m: &PreAccept| { &m.seq },
-                    |m: &mut PreAccept| { &mut m.seq },
+                    |m: &Command| { &m.seq },
+                    |m: &mut Command| { &mut m.seq },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Command>>(
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<InstanceRef>>(
                     "deps",
-                    |m: &PreAccept| { &m.deps },
-                    |m: &mut PreAccept| { &mut m.deps },
+                    |m: &Command| { &m.deps },
+                    |m: &mut Command| { &mut m.deps },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<State>>(
+                    "state",
+                    |m: &Command| { &m.state },
+                    |m: &mut Command| { &mut m.state },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "replica_id",
+                    |m: &Command| { &m.replica_id },
+                    |m: &mut Command| { &mut m.replica_id },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
                     "instance_number",
-                    |m: &PreAccept| { &m.instance_number },
-                    |m: &mut PreAccept| { &mut m.instance_number },
+                    |m: &Command| { &m.instance_number },
+                    |m: &mut Command| { &mut m.instance_number },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<PreAccept>(
-                    "PreAccept",
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                    "ballot",
+                    |m: &Command| { &m.ballot },
+                    |m: &mut Command| { &mut m.ballot },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                    "accepted_ballot",
+                    |m: &Command| { &m.accepted_ballot },
+                    |m: &mut Command| { &mut m.accepted_ballot },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Command>(
+                    "Command",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1543,69 +1741,73 @@ impl ::protobuf::Message for PreAccept {
         }
     }
 
-    fn default_instance() -> &'static PreAccept {
-        static mut instance: ::protobuf::lazy::Lazy<PreAccept> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static Command {
+        static mut instance: ::protobuf::lazy::Lazy<Command> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const PreAccept,
+            ptr: 0 as *const Command,
         };
         unsafe {
-            instance.get(PreAccept::new)
+            instance.get(Command::new)
         }
     }
 }
 
-impl ::protobuf::Clear for PreAccept {
+impl ::protobuf::Clear for Command {
     fn clear(&mut self) {
-        self.replica_id = 0;
         self.ClientRequest = ::std::option::Option::None;
         self.ClientRequest = ::std::option::Option::None;
         self.seq = 0;
         self.deps.clear();
+        self.state = State::NONE;
+        self.replica_id = 0;
         self.instance_number = 0;
+        self.ballot.clear();
+        self.accepted_ballot.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for PreAccept {
+impl ::std::fmt::Debug for Command {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for PreAccept {
+impl ::protobuf::reflect::ProtobufValue for Command {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct PreAcceptOK {
+pub struct PreAccept {
     // message fields
     pub replica_id: i32,
     pub seq: i32,
-    pub deps: ::protobuf::RepeatedField<Command>,
+    pub deps: ::protobuf::RepeatedField<InstanceRef>,
     pub instance_number: i32,
+    pub ballot: ::protobuf::SingularPtrField<Ballot>,
     // message oneof groups
-    pub ClientRequest: ::std::option::Option<PreAcceptOK_oneof_ClientRequest>,
+    pub ClientRequest: ::std::option::Option<PreAccept_oneof_ClientRequest>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a PreAcceptOK {
-    fn default() -> &'a PreAcceptOK {
-        <PreAcceptOK as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a PreAccept {
+    fn default() -> &'a PreAccept {
+        <PreAccept as ::protobuf::Message>::default_instance()
     }
 }
 
 #[derive(Clone,PartialEq,Debug)]
-pub enum PreAcceptOK_oneof_ClientRequest {
+pub enum PreAccept_oneof_ClientRequest {
     write_req(WriteRequest),
     read_req(ReadRequest),
 }
 
-impl PreAcceptOK {
-    pub fn new() -> PreAcceptOK {
+impl PreAccept {
+    pub fn new() -> PreAccept {
         ::std::default::Default::default()
     }
 
@@ -1629,7 +1831,7 @@ impl PreAcceptOK {
 
     pub fn get_write_req(&self) -> &WriteRequest {
         match self.ClientRequest {
-            ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::write_req(ref v)) => v,
+            ::std::option::Option::Some(PreAccept_oneof_ClientRequest::write_req(ref v)) => v,
             _ => WriteRequest::default_instance(),
         }
     }
@@ -1639,24 +1841,24 @@ impl PreAcceptOK {
 
     pub fn has_write_req(&self) -> bool {
         match self.ClientRequest {
-            ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::write_req(..)) => true,
+            ::std::option::Option::Some(PreAccept_oneof_ClientRequest::write_req(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
     pub fn set_write_req(&mut self, v: WriteRequest) {
-        self.ClientRequest = ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::write_req(v))
+        self.ClientRequest = ::std::option::Option::Some(PreAccept_oneof_ClientRequest::write_req(v))
     }
 
     // Mutable pointer to the field.
     pub fn mut_write_req(&mut self) -> &mut WriteRequest {
-        if let ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::write_req(_)) = self.ClientRequest {
+        if let ::std::option::Option::Some(PreAccept_oneof_ClientRequest::write_req(_)) = self.ClientRequest {
         } else {
-            self.ClientRequest = ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::write_req(WriteRequest::new()));
+            self.ClientRequest = ::std::option::Option::Some(PreAccept_oneof_ClientRequest::write_req(WriteRequest::new()));
         }
         match self.ClientRequest {
-            ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::write_req(ref mut v)) => v,
+            ::std::option::Option::Some(PreAccept_oneof_ClientRequest::write_req(ref mut v)) => v,
             _ => panic!(),
         }
     }
@@ -1665,7 +1867,7 @@ impl PreAcceptOK {
     pub fn take_write_req(&mut self) -> WriteRequest {
         if self.has_write_req() {
             match self.ClientRequest.take() {
-                ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::write_req(v)) => v,
+                ::std::option::Option::Some(PreAccept_oneof_ClientRequest::write_req(v)) => v,
                 _ => panic!(),
             }
         } else {
@@ -1678,7 +1880,7 @@ impl PreAcceptOK {
 
     pub fn get_read_req(&self) -> &ReadRequest {
         match self.ClientRequest {
-            ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::read_req(ref v)) => v,
+            ::std::option::Option::Some(PreAccept_oneof_ClientRequest::read_req(ref v)) => v,
             _ => ReadRequest::default_instance(),
         }
     }
@@ -1688,24 +1890,24 @@ impl PreAcceptOK {
 
     pub fn has_read_req(&self) -> bool {
         match self.ClientRequest {
-            ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::read_req(..)) => true,
+            ::std::option::Option::Some(PreAccept_oneof_ClientRequest::read_req(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
     pub fn set_read_req(&mut self, v: ReadRequest) {
-        self.ClientRequest = ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::read_req(v))
+        self.ClientRequest = ::std::option::Option::Some(PreAccept_oneof_ClientRequest::read_req(v))
     }
 
     // Mutable pointer to the field.
     pub fn mut_read_req(&mut self) -> &mut ReadRequest {
-        if let ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::read_req(_)) = self.ClientRequest {
+        if let ::std::option::Option::Some(PreAccept_oneof_ClientRequest::read_req(_)) = self.ClientRequest {
         } else {
-            self.ClientRequest = ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::read_req(ReadRequest::new()));
+            self.ClientRequest = ::std::option::Option::Some(PreAccept_oneof_ClientRequest::read_req(ReadRequest::new()));
         }
         match self.ClientRequest {
-            ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::read_req(ref mut v)) => v,
+            ::std::option::Option::Some(PreAccept_oneof_ClientRequest::read_req(ref mut v)) => v,
             _ => panic!(),
         }
     }
@@ -1714,7 +1916,7 @@ impl PreAcceptOK {
     pub fn take_read_req(&mut self) -> ReadRequest {
         if self.has_read_req() {
             match self.ClientRequest.take() {
-                ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::read_req(v)) => v,
+                ::std::option::Option::Some(PreAccept_oneof_ClientRequest::read_req(v)) => v,
                 _ => panic!(),
             }
         } else {
@@ -1737,10 +1939,10 @@ impl PreAcceptOK {
         self.seq = v;
     }
 
-    // repeated .epaxos.Command deps = 5;
+    // repeated .epaxos.InstanceRef deps = 5;
 
 
-    pub fn get_deps(&self) -> &[Command] {
+    pub fn get_deps(&self) -> &[InstanceRef] {
         &self.deps
     }
     pub fn clear_deps(&mut self) {
@@ -1748,17 +1950,17 @@ impl PreAcceptOK {
     }
 
     // Param is passed by value, moved
-    pub fn set_deps(&mut self, v: ::protobuf::RepeatedField<Command>) {
+    pub fn set_deps(&mut self, v: ::protobuf::RepeatedField<InstanceRef>) {
         self.deps = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_deps(&mut self) -> &mut ::protobuf::RepeatedField<Command> {
+    pub fn mut_deps(&mut self) -> &mut ::protobuf::RepeatedField<InstanceRef> {
         &mut self.deps
     }
 
     // Take field
-    pub fn take_deps(&mut self) -> ::protobuf::RepeatedField<Command> {
+    pub fn take_deps(&mut self) -> ::protobuf::RepeatedField<InstanceRef> {
         ::std::mem::replace(&mut self.deps, ::protobuf::RepeatedField::new())
     }
 
@@ -1776,16 +1978,49 @@ impl PreAcceptOK {
     pub fn set_instance_number(&mut self, v: i32) {
         self.instance_number = v;
     }
-}
 
-impl ::protobuf::Message for PreAcceptOK {
-    fn is_initialized(&self) -> bool {
-        if let Some(PreAcceptOK_oneof_ClientRequest::write_req(ref v)) = self.ClientRequest {
+    // .epaxos.Ballot ballot = 7;
+
+
+    pub fn get_ballot(&self) -> &Ballot {
+        self.ballot.as_ref().unwrap_or_else(|| Ballot::default_instance())
+    }
+    pub fn clear_ballot(&mut self) {
+        self.ballot.clear();
+    }
+
+    pub fn has_ballot(&self) -> bool {
+        self.ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ballot(&mut self, v: Ballot) {
+        self.ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ballot(&mut self) -> &mut Ballot {
+        if self.ballot.is_none() {
+            self.ballot.set_default();
+        }
+        self.ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ballot(&mut self) -> Ballot {
+        self.ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+}
+
+impl ::protobuf::Message for PreAccept {
+    fn is_initialized(&self) -> bool {
+        if let Some(PreAccept_oneof_ClientRequest::write_req(ref v)) = self.ClientRequest {
             if !v.is_initialized() {
                 return false;
             }
         }
-        if let Some(PreAcceptOK_oneof_ClientRequest::read_req(ref v)) = self.ClientRequest {
+        if let Some(PreAccept_oneof_ClientRequest::read_req(ref v)) = self.ClientRequest {
             if !v.is_initialized() {
                 return false;
             }
@@ -1795,6 +2030,11 @@ impl ::protobuf::Message for PreAcceptOK {
                 return false;
             }
         };
+        for v in &self.ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -1813,13 +2053,13 @@ impl ::protobuf::Message for PreAcceptOK {
                     if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.ClientRequest = ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::write_req(is.read_message()?));
+                    self.ClientRequest = ::std::option::Option::Some(PreAccept_oneof_ClientRequest::write_req(is.read_message()?));
                 },
                 3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.ClientRequest = ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::read_req(is.read_message()?));
+                    self.ClientRequest = ::std::option::Option::Some(PreAccept_oneof_ClientRequest::read_req(is.read_message()?));
                 },
                 4 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
@@ -1838,6 +2078,9 @@ impl ::protobuf::Message for PreAcceptOK {
                     let tmp = is.read_int32()?;
                     self.instance_number = tmp;
                 },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ballot)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1863,13 +2106,17 @@ impl ::protobuf::Message for PreAcceptOK {
         if self.instance_number != 0 {
             my_size += ::protobuf::rt::value_size(6, self.instance_number, ::protobuf::wire_format::WireTypeVarint);
         }
+        if let Some(ref v) = self.ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         if let ::std::option::Option::Some(ref v) = self.ClientRequest {
             match v {
-                &PreAcceptOK_oneof_ClientRequest::write_req(ref v) => {
+                &PreAccept_oneof_ClientRequest::write_req(ref v) => {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
-                &PreAcceptOK_oneof_ClientRequest::read_req(ref v) => {
+                &PreAccept_oneof_ClientRequest::read_req(ref v) => {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
@@ -1895,14 +2142,19 @@ impl ::protobuf::Message for PreAcceptOK {
         if self.instance_number != 0 {
             os.write_int32(6, self.instance_number)?;
         }
+        if let Some(ref v) = self.ballot.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         if let ::std::option::Option::Some(ref v) = self.ClientRequest {
             match v {
-                &PreAcceptOK_oneof_ClientRequest::write_req(ref v) => {
+                &PreAccept_oneof_ClientRequest::write_req(ref v) => {
                     os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
-                &PreAcceptOK_oneof_ClientRequest::read_req(ref v) => {
+                &PreAccept_oneof_ClientRequest::read_req(ref v) => {
                     os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
@@ -1939,8 +2191,8 @@ impl ::protobuf::Message for PreAcceptOK {
         Self::descriptor_static()
     }
 
-    fn new() -> PreAcceptOK {
-        PreAcceptOK::new()
+    fn new() -> PreAccept {
+        PreAccept::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1953,36 +2205,41 @@ impl ::protobuf::Message for PreAcceptOK {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
                     "replica_id",
-                    |m: &PreAcceptOK| { &m.replica_id },
-                    |m: &mut PreAcceptOK| { &mut m.replica_id },
+                    |m: &PreAccept| { &m.replica_id },
+                    |m: &mut PreAccept| { &mut m.replica_id },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WriteRequest>(
                     "write_req",
-                    PreAcceptOK::has_write_req,
-                    PreAcceptOK::get_write_req,
+                    PreAccept::has_write_req,
+                    PreAccept::get_write_req,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ReadRequest>(
                     "read_req",
-                    PreAcceptOK::has_read_req,
-                    PreAcceptOK::get_read_req,
+                    PreAccept::has_read_req,
+                    PreAccept::get_read_req,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
                     "seq",
-                    |m: &PreAcceptOK| { &m.seq },
-                    |m: &mut PreAcceptOK| { &mut m.seq },
+                    |m: &PreAccept| { &m.seq },
+                    |m: &mut PreAccept| { &mut m.seq },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Command>>(
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<InstanceRef>>(
                     "deps",
-                    |m: &PreAcceptOK| { &m.deps },
-                    |m: &mut PreAcceptOK| { &mut m.deps },
+                    |m: &PreAccept| { &m.deps },
+                    |m: &mut PreAccept| { &mut m.deps },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
                     "instance_number",
-                    |m: &PreAcceptOK| { &m.instance_number },
-                    |m: &mut PreAcceptOK| { &mut m.instance_number },
+                    |m: &PreAccept| { &m.instance_number },
+                    |m: &mut PreAccept| { &mut m.instance_number },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<PreAcceptOK>(
-                    "PreAcceptOK",
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                    "ballot",
+                    |m: &PreAccept| { &m.ballot },
+                    |m: &mut PreAccept| { &mut m.ballot },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PreAccept>(
+                    "PreAccept",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1990,18 +2247,18 @@ impl ::protobuf::Message for PreAcceptOK {
         }
     }
 
-    fn default_instance() -> &'static PreAcceptOK {
-        static mut instance: ::protobuf::lazy::Lazy<PreAcceptOK> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static PreAccept {
+        static mut instance: ::protobuf::lazy::Lazy<PreAccept> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const PreAcceptOK,
+            ptr: 0 as *const PreAccept,
         };
         unsafe {
-            instance.get(PreAcceptOK::new)
+            instance.get(PreAccept::new)
         }
     }
 }
 
-impl ::protobuf::Clear for PreAcceptOK {
+impl ::protobuf::Clear for PreAccept {
     fn clear(&mut self) {
         self.replica_id = 0;
         self.ClientRequest = ::std::option::Option::None;
@@ -2009,50 +2266,53 @@ impl ::protobuf::Clear for PreAcceptOK {
         self.seq = 0;
         self.deps.clear();
         self.instance_number = 0;
+        self.ballot.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for PreAcceptOK {
+impl ::std::fmt::Debug for PreAccept {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for PreAcceptOK {
+impl ::protobuf::reflect::ProtobufValue for PreAccept {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Commit {
+pub struct PreAcceptOK {
     // message fields
     pub replica_id: i32,
     pub seq: i32,
-    pub deps: ::protobuf::RepeatedField<Command>,
+    pub deps: ::protobuf::RepeatedField<InstanceRef>,
     pub instance_number: i32,
+    pub ballot: ::protobuf::SingularPtrField<Ballot>,
+    pub ok: bool,
     // message oneof groups
-    pub ClientRequest: ::std::option::Option<Commit_oneof_ClientRequest>,
+    pub ClientRequest: ::std::option::Option<PreAcceptOK_oneof_ClientRequest>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Commit {
-    fn default() -> &'a Commit {
-        <Commit as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a PreAcceptOK {
+    fn default() -> &'a PreAcceptOK {
+        <PreAcceptOK as ::protobuf::Message>::default_instance()
     }
 }
 
 #[derive(Clone,PartialEq,Debug)]
-pub enum Commit_oneof_ClientRequest {
+pub enum PreAcceptOK_oneof_ClientRequest {
     write_req(WriteRequest),
     read_req(ReadRequest),
 }
 
-impl Commit {
-    pub fn new() -> Commit {
+impl PreAcceptOK {
+    pub fn new() -> PreAcceptOK {
         ::std::default::Default::default()
     }
 
@@ -2076,7 +2336,7 @@ impl Commit {
 
     pub fn get_write_req(&self) -> &WriteRequest {
         match self.ClientRequest {
-            ::std::option::Option::Some(Commit_oneof_ClientRequest::write_req(ref v)) => v,
+            ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::write_req(ref v)) => v,
             _ => WriteRequest::default_instance(),
         }
     }
@@ -2086,24 +2346,24 @@ impl Commit {
 
     pub fn has_write_req(&self) -> bool {
         match self.ClientRequest {
-            ::std::option::Option::Some(Commit_oneof_ClientRequest::write_req(..)) => true,
+            ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::write_req(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
     pub fn set_write_req(&mut self, v: WriteRequest) {
-        self.ClientRequest = ::std::option::Option::Some(Commit_oneof_ClientRequest::write_req(v))
+        self.ClientRequest = ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::write_req(v))
     }
 
     // Mutable pointer to the field.
     pub fn mut_write_req(&mut self) -> &mut WriteRequest {
-        if let ::std::option::Option::Some(Commit_oneof_ClientRequest::write_req(_)) = self.ClientRequest {
+        if let ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::write_req(_)) = self.ClientRequest {
         } else {
-            self.ClientRequest = ::std::option::Option::Some(Commit_oneof_ClientRequest::write_req(WriteRequest::new()));
+            self.ClientRequest = ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::write_req(WriteRequest::new()));
         }
         match self.ClientRequest {
-            ::std::option::Option::Some(Commit_oneof_ClientRequest::write_req(ref mut v)) => v,
+            ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::write_req(ref mut v)) => v,
             _ => panic!(),
         }
     }
@@ -2112,7 +2372,7 @@ impl Commit {
     pub fn take_write_req(&mut self) -> WriteRequest {
         if self.has_write_req() {
             match self.ClientRequest.take() {
-                ::std::option::Option::Some(Commit_oneof_ClientRequest::write_req(v)) => v,
+                ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::write_req(v)) => v,
                 _ => panic!(),
             }
         } else {
@@ -2125,7 +2385,7 @@ impl Commit {
 
     pub fn get_read_req(&self) -> &ReadRequest {
         match self.ClientRequest {
-            ::std::option::Option::Some(Commit_oneof_ClientRequest::read_req(ref v)) => v,
+            ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::read_req(ref v)) => v,
             _ => ReadRequest::default_instance(),
         }
     }
@@ -2135,113 +2395,3771 @@ impl Commit {
 
     pub fn has_read_req(&self) -> bool {
         match self.ClientRequest {
-            ::std::option::Option::Some(Commit_oneof_ClientRequest::read_req(..)) => true,
+            ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::read_req(..)) => true,
             _ => false,
         }
     }
 
-    // Param is passed by value, moved
-    pub fn set_read_req(&mut self, v: ReadRequest) {
-        self.ClientRequest = ::std::option::Option::Some(Commit_oneof_ClientRequest::read_req(v))
+    // Param is passed by value, moved
+    pub fn set_read_req(&mut self, v: ReadRequest) {
+        self.ClientRequest = ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::read_req(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_read_req(&mut self) -> &mut ReadRequest {
+        if let ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::read_req(_)) = self.ClientRequest {
+        } else {
+            self.ClientRequest = ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::read_req(ReadRequest::new()));
+        }
+        match self.ClientRequest {
+            ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::read_req(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_read_req(&mut self) -> ReadRequest {
+        if self.has_read_req() {
+            match self.ClientRequest.take() {
+                ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::read_req(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ReadRequest::new()
+        }
+    }
+
+    // int32 seq = 4;
+
+
+    pub fn get_seq(&self) -> i32 {
+        self.seq
+    }
+    pub fn clear_seq(&mut self) {
+        self.seq = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seq(&mut self, v: i32) {
+        self.seq = v;
+    }
+
+    // repeated .epaxos.InstanceRef deps = 5;
+
+
+    pub fn get_deps(&self) -> &[InstanceRef] {
+        &self.deps
+    }
+    pub fn clear_deps(&mut self) {
+        self.deps.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deps(&mut self, v: ::protobuf::RepeatedField<InstanceRef>) {
+        self.deps = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_deps(&mut self) -> &mut ::protobuf::RepeatedField<InstanceRef> {
+        &mut self.deps
+    }
+
+    // Take field
+    pub fn take_deps(&mut self) -> ::protobuf::RepeatedField<InstanceRef> {
+        ::std::mem::replace(&mut self.deps, ::protobuf::RepeatedField::new())
+    }
+
+    // int32 instance_number = 6;
+
+
+    pub fn get_instance_number(&self) -> i32 {
+        self.instance_number
+    }
+    pub fn clear_instance_number(&mut self) {
+        self.instance_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_instance_number(&mut self, v: i32) {
+        self.instance_number = v;
+    }
+
+    // .epaxos.Ballot ballot = 7;
+
+
+    pub fn get_ballot(&self) -> &Ballot {
+        self.ballot.as_ref().unwrap_or_else(|| Ballot::default_instance())
+    }
+    pub fn clear_ballot(&mut self) {
+        self.ballot.clear();
+    }
+
+    pub fn has_ballot(&self) -> bool {
+        self.ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ballot(&mut self, v: Ballot) {
+        self.ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ballot(&mut self) -> &mut Ballot {
+        if self.ballot.is_none() {
+            self.ballot.set_default();
+        }
+        self.ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ballot(&mut self) -> Ballot {
+        self.ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+
+    // bool ok = 8;
+
+
+    pub fn get_ok(&self) -> bool {
+        self.ok
+    }
+    pub fn clear_ok(&mut self) {
+        self.ok = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ok(&mut self, v: bool) {
+        self.ok = v;
+    }
+}
+
+impl ::protobuf::Message for PreAcceptOK {
+    fn is_initialized(&self) -> bool {
+        if let Some(PreAcceptOK_oneof_ClientRequest::write_req(ref v)) = self.ClientRequest {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(PreAcceptOK_oneof_ClientRequest::read_req(ref v)) = self.ClientRequest {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        for v in &self.deps {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.replica_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.ClientRequest = ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::write_req(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.ClientRequest = ::std::option::Option::Some(PreAcceptOK_oneof_ClientRequest::read_req(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.seq = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.deps)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.instance_number = tmp;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ballot)?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.ok = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.replica_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.replica_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.seq != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.seq, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.deps {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.instance_number != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.instance_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.ok != false {
+            my_size += 2;
+        }
+        if let ::std::option::Option::Some(ref v) = self.ClientRequest {
+            match v {
+                &PreAcceptOK_oneof_ClientRequest::write_req(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &PreAcceptOK_oneof_ClientRequest::read_req(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.replica_id != 0 {
+            os.write_int32(1, self.replica_id)?;
+        }
+        if self.seq != 0 {
+            os.write_int32(4, self.seq)?;
+        }
+        for v in &self.deps {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.instance_number != 0 {
+            os.write_int32(6, self.instance_number)?;
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.ok != false {
+            os.write_bool(8, self.ok)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.ClientRequest {
+            match v {
+                &PreAcceptOK_oneof_ClientRequest::write_req(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &PreAcceptOK_oneof_ClientRequest::read_req(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PreAcceptOK {
+        PreAcceptOK::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "replica_id",
+                    |m: &PreAcceptOK| { &m.replica_id },
+                    |m: &mut PreAcceptOK| { &mut m.replica_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WriteRequest>(
+                    "write_req",
+                    PreAcceptOK::has_write_req,
+                    PreAcceptOK::get_write_req,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ReadRequest>(
+                    "read_req",
+                    PreAcceptOK::has_read_req,
+                    PreAcceptOK::get_read_req,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "seq",
+                    |m: &PreAcceptOK| { &m.seq },
+                    |m: &mut PreAcceptOK| { &mut m.seq },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<InstanceRef>>(
+                    "deps",
+                    |m: &PreAcceptOK| { &m.deps },
+                    |m: &mut PreAcceptOK| { &mut m.deps },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "instance_number",
+                    |m: &PreAcceptOK| { &m.instance_number },
+                    |m: &mut PreAcceptOK| { &mut m.instance_number },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                    "ballot",
+                    |m: &PreAcceptOK| { &m.ballot },
+                    |m: &mut PreAcceptOK| { &mut m.ballot },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "ok",
+                    |m: &PreAcceptOK| { &m.ok },
+                    |m: &mut PreAcceptOK| { &mut m.ok },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PreAcceptOK>(
+                    "PreAcceptOK",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PreAcceptOK {
+        static mut instance: ::protobuf::lazy::Lazy<PreAcceptOK> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PreAcceptOK,
+        };
+        unsafe {
+            instance.get(PreAcceptOK::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PreAcceptOK {
+    fn clear(&mut self) {
+        self.replica_id = 0;
+        self.ClientRequest = ::std::option::Option::None;
+        self.ClientRequest = ::std::option::Option::None;
+        self.seq = 0;
+        self.deps.clear();
+        self.instance_number = 0;
+        self.ballot.clear();
+        self.ok = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PreAcceptOK {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PreAcceptOK {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Accept {
+    // message fields
+    pub replica_id: i32,
+    pub seq: i32,
+    pub deps: ::protobuf::RepeatedField<InstanceRef>,
+    pub instance_number: i32,
+    pub ballot: ::protobuf::SingularPtrField<Ballot>,
+    // message oneof groups
+    pub ClientRequest: ::std::option::Option<Accept_oneof_ClientRequest>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Accept {
+    fn default() -> &'a Accept {
+        <Accept as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum Accept_oneof_ClientRequest {
+    write_req(WriteRequest),
+    read_req(ReadRequest),
+}
+
+impl Accept {
+    pub fn new() -> Accept {
+        ::std::default::Default::default()
+    }
+
+    // int32 replica_id = 1;
+
+
+    pub fn get_replica_id(&self) -> i32 {
+        self.replica_id
+    }
+    pub fn clear_replica_id(&mut self) {
+        self.replica_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_replica_id(&mut self, v: i32) {
+        self.replica_id = v;
+    }
+
+    // .epaxos.WriteRequest write_req = 2;
+
+
+    pub fn get_write_req(&self) -> &WriteRequest {
+        match self.ClientRequest {
+            ::std::option::Option::Some(Accept_oneof_ClientRequest::write_req(ref v)) => v,
+            _ => WriteRequest::default_instance(),
+        }
+    }
+    pub fn clear_write_req(&mut self) {
+        self.ClientRequest = ::std::option::Option::None;
+    }
+
+    pub fn has_write_req(&self) -> bool {
+        match self.ClientRequest {
+            ::std::option::Option::Some(Accept_oneof_ClientRequest::write_req(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_write_req(&mut self, v: WriteRequest) {
+        self.ClientRequest = ::std::option::Option::Some(Accept_oneof_ClientRequest::write_req(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_write_req(&mut self) -> &mut WriteRequest {
+        if let ::std::option::Option::Some(Accept_oneof_ClientRequest::write_req(_)) = self.ClientRequest {
+        } else {
+            self.ClientRequest = ::std::option::Option::Some(Accept_oneof_ClientRequest::write_req(WriteRequest::new()));
+        }
+        match self.ClientRequest {
+            ::std::option::Option::Some(Accept_oneof_ClientRequest::write_req(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_write_req(&mut self) -> WriteRequest {
+        if self.has_write_req() {
+            match self.ClientRequest.take() {
+                ::std::option::Option::Some(Accept_oneof_ClientRequest::write_req(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            WriteRequest::new()
+        }
+    }
+
+    // .epaxos.ReadRequest read_req = 3;
+
+
+    pub fn get_read_req(&self) -> &ReadRequest {
+        match self.ClientRequest {
+            ::std::option::Option::Some(Accept_oneof_ClientRequest::read_req(ref v)) => v,
+            _ => ReadRequest::default_instance(),
+        }
+    }
+    pub fn clear_read_req(&mut self) {
+        self.ClientRequest = ::std::option::Option::None;
+    }
+
+    pub fn has_read_req(&self) -> bool {
+        match self.ClientRequest {
+            ::std::option::Option::Some(Accept_oneof_ClientRequest::read_req(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_req(&mut self, v: ReadRequest) {
+        self.ClientRequest = ::std::option::Option::Some(Accept_oneof_ClientRequest::read_req(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_read_req(&mut self) -> &mut ReadRequest {
+        if let ::std::option::Option::Some(Accept_oneof_ClientRequest::read_req(_)) = self.ClientRequest {
+        } else {
+            self.ClientRequest = ::std::option::Option::Some(Accept_oneof_ClientRequest::read_req(ReadRequest::new()));
+        }
+        match self.ClientRequest {
+            ::std::option::Option::Some(Accept_oneof_ClientRequest::read_req(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_read_req(&mut self) -> ReadRequest {
+        if self.has_read_req() {
+            match self.ClientRequest.take() {
+                ::std::option::Option::Some(Accept_oneof_ClientRequest::read_req(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ReadRequest::new()
+        }
+    }
+
+    // int32 seq = 4;
+
+
+    pub fn get_seq(&self) -> i32 {
+        self.seq
+    }
+    pub fn clear_seq(&mut self) {
+        self.seq = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seq(&mut self, v: i32) {
+        self.seq = v;
+    }
+
+    // repeated .epaxos.InstanceRef deps = 5;
+
+
+    pub fn get_deps(&self) -> &[InstanceRef] {
+        &self.deps
+    }
+    pub fn clear_deps(&mut self) {
+        self.deps.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deps(&mut self, v: ::protobuf::RepeatedField<InstanceRef>) {
+        self.deps = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_deps(&mut self) -> &mut ::protobuf::RepeatedField<InstanceRef> {
+        &mut self.deps
+    }
+
+    // Take field
+    pub fn take_deps(&mut self) -> ::protobuf::RepeatedField<InstanceRef> {
+        ::std::mem::replace(&mut self.deps, ::protobuf::RepeatedField::new())
+    }
+
+    // int32 instance_number = 6;
+
+
+    pub fn get_instance_number(&self) -> i32 {
+        self.instance_number
+    }
+    pub fn clear_instance_number(&mut self) {
+        self.instance_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_instance_number(&mut self, v: i32) {
+        self.instance_number = v;
+    }
+
+    // .epaxos.Ballot ballot = 7;
+
+
+    pub fn get_ballot(&self) -> &Ballot {
+        self.ballot.as_ref().unwrap_or_else(|| Ballot::default_instance())
+    }
+    pub fn clear_ballot(&mut self) {
+        self.ballot.clear();
+    }
+
+    pub fn has_ballot(&self) -> bool {
+        self.ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ballot(&mut self, v: Ballot) {
+        self.ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ballot(&mut self) -> &mut Ballot {
+        if self.ballot.is_none() {
+            self.ballot.set_default();
+        }
+        self.ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ballot(&mut self) -> Ballot {
+        self.ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+}
+
+impl ::protobuf::Message for Accept {
+    fn is_initialized(&self) -> bool {
+        if let Some(Accept_oneof_ClientRequest::write_req(ref v)) = self.ClientRequest {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Accept_oneof_ClientRequest::read_req(ref v)) = self.ClientRequest {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        for v in &self.deps {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.replica_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.ClientRequest = ::std::option::Option::Some(Accept_oneof_ClientRequest::write_req(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.ClientRequest = ::std::option::Option::Some(Accept_oneof_ClientRequest::read_req(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.seq = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.deps)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.instance_number = tmp;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ballot)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.replica_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.replica_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.seq != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.seq, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.deps {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.instance_number != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.instance_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.ClientRequest {
+            match v {
+                &Accept_oneof_ClientRequest::write_req(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Accept_oneof_ClientRequest::read_req(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.replica_id != 0 {
+            os.write_int32(1, self.replica_id)?;
+        }
+        if self.seq != 0 {
+            os.write_int32(4, self.seq)?;
+        }
+        for v in &self.deps {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.instance_number != 0 {
+            os.write_int32(6, self.instance_number)?;
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.ClientRequest {
+            match v {
+                &Accept_oneof_ClientRequest::write_req(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Accept_oneof_ClientRequest::read_req(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Accept {
+        Accept::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "replica_id",
+                    |m: &Accept| { &m.replica_id },
+                    |m: &mut Accept| { &mut m.replica_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WriteRequest>(
+                    "write_req",
+                    Accept::has_write_req,
+                    Accept::get_write_req,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ReadRequest>(
+                    "read_req",
+                    Accept::has_read_req,
+                    Accept::get_read_req,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "seq",
+                    |m: &Accept| { &m.seq },
+                    |m: &mut Accept| { &mut m.seq },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<InstanceRef>>(
+                    "deps",
+                    |m: &Accept| { &m.deps },
+                    |m: &mut Accept| { &mut m.deps },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "instance_number",
+                    |m: &Accept| { &m.instance_number },
+                    |m: &mut Accept| { &mut m.instance_number },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                    "ballot",
+                    |m: &Accept| { &m.ballot },
+                    |m: &mut Accept| { &mut m.ballot },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Accept>(
+                    "Accept",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Accept {
+        static mut instance: ::protobuf::lazy::Lazy<Accept> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Accept,
+        };
+        unsafe {
+            instance.get(Accept::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Accept {
+    fn clear(&mut self) {
+        self.replica_id = 0;
+        self.ClientRequest = ::std::option::Option::None;
+        self.ClientRequest = ::std::option::Option::None;
+        self.seq = 0;
+        self.deps.clear();
+        self.instance_number = 0;
+        self.ballot.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Accept {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Accept {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AcceptOK {
+    // message fields
+    pub replica_id: i32,
+    pub instance_number: i32,
+    pub ok: bool,
+    pub ballot: ::protobuf::SingularPtrField<Ballot>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AcceptOK {
+    fn default() -> &'a AcceptOK {
+        <AcceptOK as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AcceptOK {
+    pub fn new() -> AcceptOK {
+        ::std::default::Default::default()
+    }
+
+    // int32 replica_id = 1;
+
+
+    pub fn get_replica_id(&self) -> i32 {
+        self.replica_id
+    }
+    pub fn clear_replica_id(&mut self) {
+        self.replica_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_replica_id(&mut self, v: i32) {
+        self.replica_id = v;
+    }
+
+    // int32 instance_number = 2;
+
+
+    pub fn get_instance_number(&self) -> i32 {
+        self.instance_number
+    }
+    pub fn clear_instance_number(&mut self) {
+        self.instance_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_instance_number(&mut self, v: i32) {
+        self.instance_number = v;
+    }
+
+    // bool ok = 3;
+
+
+    pub fn get_ok(&self) -> bool {
+        self.ok
+    }
+    pub fn clear_ok(&mut self) {
+        self.ok = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ok(&mut self, v: bool) {
+        self.ok = v;
+    }
+
+    // .epaxos.Ballot ballot = 4;
+
+
+    pub fn get_ballot(&self) -> &Ballot {
+        self.ballot.as_ref().unwrap_or_else(|| Ballot::default_instance())
+    }
+    pub fn clear_ballot(&mut self) {
+        self.ballot.clear();
+    }
+
+    pub fn has_ballot(&self) -> bool {
+        self.ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ballot(&mut self, v: Ballot) {
+        self.ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ballot(&mut self) -> &mut Ballot {
+        if self.ballot.is_none() {
+            self.ballot.set_default();
+        }
+        self.ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ballot(&mut self) -> Ballot {
+        self.ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+}
+
+impl ::protobuf::Message for AcceptOK {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.replica_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.instance_number = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.ok = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ballot)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.replica_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.replica_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.instance_number != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.instance_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ok != false {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.replica_id != 0 {
+            os.write_int32(1, self.replica_id)?;
+        }
+        if self.instance_number != 0 {
+            os.write_int32(2, self.instance_number)?;
+        }
+        if self.ok != false {
+            os.write_bool(3, self.ok)?;
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AcceptOK {
+        AcceptOK::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "replica_id",
+                    |m: &AcceptOK| { &m.replica_id },
+                    |m: &mut AcceptOK| { &mut m.replica_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "instance_number",
+                    |m: &AcceptOK| { &m.instance_number },
+                    |m: &mut AcceptOK| { &mut m.instance_number },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "ok",
+                    |m: &AcceptOK| { &m.ok },
+                    |m: &mut AcceptOK| { &mut m.ok },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                    "ballot",
+                    |m: &AcceptOK| { &m.ballot },
+                    |m: &mut AcceptOK| { &mut m.ballot },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AcceptOK>(
+                    "AcceptOK",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static AcceptOK {
+        static mut instance: ::protobuf::lazy::Lazy<AcceptOK> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AcceptOK,
+        };
+        unsafe {
+            instance.get(AcceptOK::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for AcceptOK {
+    fn clear(&mut self) {
+        self.replica_id = 0;
+        self.instance_number = 0;
+        self.ok = false;
+        self.ballot.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AcceptOK {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AcceptOK {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Commit {
+    // message fields
+    pub replica_id: i32,
+    pub seq: i32,
+    pub deps: ::protobuf::RepeatedField<InstanceRef>,
+    pub instance_number: i32,
+    pub ballot: ::protobuf::SingularPtrField<Ballot>,
+    // message oneof groups
+    pub ClientRequest: ::std::option::Option<Commit_oneof_ClientRequest>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Commit {
+    fn default() -> &'a Commit {
+        <Commit as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum Commit_oneof_ClientRequest {
+    write_req(WriteRequest),
+    read_req(ReadRequest),
+}
+
+impl Commit {
+    pub fn new() -> Commit {
+        ::std::default::Default::default()
+    }
+
+    // int32 replica_id = 1;
+
+
+    pub fn get_replica_id(&self) -> i32 {
+        self.replica_id
+    }
+    pub fn clear_replica_id(&mut self) {
+        self.replica_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_replica_id(&mut self, v: i32) {
+        self.replica_id = v;
+    }
+
+    // .epaxos.WriteRequest write_req = 2;
+
+
+    pub fn get_write_req(&self) -> &WriteRequest {
+        match self.ClientRequest {
+            ::std::option::Option::Some(Commit_oneof_ClientRequest::write_req(ref v)) => v,
+            _ => WriteRequest::default_instance(),
+        }
+    }
+    pub fn clear_write_req(&mut self) {
+        self.ClientRequest = ::std::option::Option::None;
+    }
+
+    pub fn has_write_req(&self) -> bool {
+        match self.ClientRequest {
+            ::std::option::Option::Some(Commit_oneof_ClientRequest::write_req(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_write_req(&mut self, v: WriteRequest) {
+        self.ClientRequest = ::std::option::Option::Some(Commit_oneof_ClientRequest::write_req(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_write_req(&mut self) -> &mut WriteRequest {
+        if let ::std::option::Option::Some(Commit_oneof_ClientRequest::write_req(_)) = self.ClientRequest {
+        } else {
+            self.ClientRequest = ::std::option::Option::Some(Commit_oneof_ClientRequest::write_req(WriteRequest::new()));
+        }
+        match self.ClientRequest {
+            ::std::option::Option::Some(Commit_oneof_ClientRequest::write_req(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_write_req(&mut self) -> WriteRequest {
+        if self.has_write_req() {
+            match self.ClientRequest.take() {
+                ::std::option::Option::Some(Commit_oneof_ClientRequest::write_req(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            WriteRequest::new()
+        }
+    }
+
+    // .epaxos.ReadRequest read_req = 3;
+
+
+    pub fn get_read_req(&self) -> &ReadRequest {
+        match self.ClientRequest {
+            ::std::option::Option::Some(Commit_oneof_ClientRequest::read_req(ref v)) => v,
+            _ => ReadRequest::default_instance(),
+        }
+    }
+    pub fn clear_read_req(&mut self) {
+        self.ClientRequest = ::std::option::Option::None;
+    }
+
+    pub fn has_read_req(&self) -> bool {
+        match self.ClientRequest {
+            ::std::option::Option::Some(Commit_oneof_ClientRequest::read_req(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_req(&mut self, v: ReadRequest) {
+        self.ClientRequest = ::std::option::Option::Some(Commit_oneof_ClientRequest::read_req(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_read_req(&mut self) -> &mut ReadRequest {
+        if let ::std::option::Option::Some(Commit_oneof_ClientRequest::read_req(_)) = self.ClientRequest {
+        } else {
+            self.ClientRequest = ::std::option::Option::Some(Commit_oneof_ClientRequest::read_req(ReadRequest::new()));
+        }
+        match self.ClientRequest {
+            ::std::option::Option::Some(Commit_oneof_ClientRequest::read_req(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_read_req(&mut self) -> ReadRequest {
+        if self.has_read_req() {
+            match self.ClientRequest.take() {
+                ::std::option::Option::Some(Commit_oneof_ClientRequest::read_req(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ReadRequest::new()
+        }
+    }
+
+    // int32 seq = 4;
+
+
+    pub fn get_seq(&self) -> i32 {
+        self.seq
+    }
+    pub fn clear_seq(&mut self) {
+        self.seq = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seq(&mut self, v: i32) {
+        self.seq = v;
+    }
+
+    // repeated .epaxos.InstanceRef deps = 5;
+
+
+    pub fn get_deps(&self) -> &[InstanceRef] {
+        &self.deps
+    }
+    pub fn clear_deps(&mut self) {
+        self.deps.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deps(&mut self, v: ::protobuf::RepeatedField<InstanceRef>) {
+        self.deps = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_deps(&mut self) -> &mut ::protobuf::RepeatedField<InstanceRef> {
+        &mut self.deps
+    }
+
+    // Take field
+    pub fn take_deps(&mut self) -> ::protobuf::RepeatedField<InstanceRef> {
+        ::std::mem::replace(&mut self.deps, ::protobuf::RepeatedField::new())
+    }
+
+    // int32 instance_number = 6;
+
+
+    pub fn get_instance_number(&self) -> i32 {
+        self.instance_number
+    }
+    pub fn clear_instance_number(&mut self) {
+        self.instance_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_instance_number(&mut self, v: i32) {
+        self.instance_number = v;
+    }
+
+    // .epaxos.Ballot ballot = 7;
+
+
+    pub fn get_ballot(&self) -> &Ballot {
+        self.ballot.as_ref().unwrap_or_else(|| Ballot::default_instance())
+    }
+    pub fn clear_ballot(&mut self) {
+        self.ballot.clear();
+    }
+
+    pub fn has_ballot(&self) -> bool {
+        self.ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ballot(&mut self, v: Ballot) {
+        self.ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ballot(&mut self) -> &mut Ballot {
+        if self.ballot.is_none() {
+            self.ballot.set_default();
+        }
+        self.ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ballot(&mut self) -> Ballot {
+        self.ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+}
+
+impl ::protobuf::Message for Commit {
+    fn is_initialized(&self) -> bool {
+        if let Some(Commit_oneof_ClientRequest::write_req(ref v)) = self.ClientRequest {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Commit_oneof_ClientRequest::read_req(ref v)) = self.ClientRequest {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        for v in &self.deps {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.replica_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.ClientRequest = ::std::option::Option::Some(Commit_oneof_ClientRequest::write_req(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.ClientRequest = ::std::option::Option::Some(Commit_oneof_ClientRequest::read_req(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.seq = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.deps)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.instance_number = tmp;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ballot)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.replica_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.replica_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.seq != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.seq, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.deps {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.instance_number != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.instance_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.ClientRequest {
+            match v {
+                &Commit_oneof_ClientRequest::write_req(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Commit_oneof_ClientRequest::read_req(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.replica_id != 0 {
+            os.write_int32(1, self.replica_id)?;
+        }
+        if self.seq != 0 {
+            os.write_int32(4, self.seq)?;
+        }
+        for v in &self.deps {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.instance_number != 0 {
+            os.write_int32(6, self.instance_number)?;
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.ClientRequest {
+            match v {
+                &Commit_oneof_ClientRequest::write_req(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Commit_oneof_ClientRequest::read_req(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Commit {
+        Commit::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "replica_id",
+                    |m: &Commit| { &m.replica_id },
+                    |m: &mut Commit| { &mut m.replica_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WriteRequest>(
+                    "write_req",
+                    Commit::has_write_req,
+                    Commit::get_write_req,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ReadRequest>(
+                    "read_req",
+                    Commit::has_read_req,
+                    Commit::get_read_req,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "seq",
+                    |m: &Commit| { &m.seq },
+                    |m: &mut Commit| { &mut m.seq },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<InstanceRef>>(
+                    "deps",
+                    |m: &Commit| { &m.deps },
+                    |m: &mut Commit| { &mut m.deps },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "instance_number",
+                    |m: &Commit| { &m.instance_number },
+                    |m: &mut Commit| { &mut m.instance_number },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                    "ballot",
+                    |m: &Commit| { &m.ballot },
+                    |m: &mut Commit| { &mut m.ballot },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Commit>(
+                    "Commit",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Commit {
+        static mut instance: ::protobuf::lazy::Lazy<Commit> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Commit,
+        };
+        unsafe {
+            instance.get(Commit::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Commit {
+    fn clear(&mut self) {
+        self.replica_id = 0;
+        self.ClientRequest = ::std::option::Option::None;
+        self.ClientRequest = ::std::option::Option::None;
+        self.seq = 0;
+        self.deps.clear();
+        self.instance_number = 0;
+        self.ballot.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Commit {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Commit {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CommitOK {
+    // message fields
+    pub replica_id: i32,
+    pub instance_number: i32,
+    pub ok: bool,
+    pub ballot: ::protobuf::SingularPtrField<Ballot>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CommitOK {
+    fn default() -> &'a CommitOK {
+        <CommitOK as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CommitOK {
+    pub fn new() -> CommitOK {
+        ::std::default::Default::default()
+    }
+
+    // int32 replica_id = 1;
+
+
+    pub fn get_replica_id(&self) -> i32 {
+        self.replica_id
+    }
+    pub fn clear_replica_id(&mut self) {
+        self.replica_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_replica_id(&mut self, v: i32) {
+        self.replica_id = v;
+    }
+
+    // int32 instance_number = 2;
+
+
+    pub fn get_instance_number(&self) -> i32 {
+        self.instance_number
+    }
+    pub fn clear_instance_number(&mut self) {
+        self.instance_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_instance_number(&mut self, v: i32) {
+        self.instance_number = v;
+    }
+
+    // bool ok = 3;
+
+
+    pub fn get_ok(&self) -> bool {
+        self.ok
+    }
+    pub fn clear_ok(&mut self) {
+        self.ok = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ok(&mut self, v: bool) {
+        self.ok = v;
+    }
+
+    // .epaxos.Ballot ballot = 4;
+
+
+    pub fn get_ballot(&self) -> &Ballot {
+        self.ballot.as_ref().unwrap_or_else(|| Ballot::default_instance())
+    }
+    pub fn clear_ballot(&mut self) {
+        self.ballot.clear();
+    }
+
+    pub fn has_ballot(&self) -> bool {
+        self.ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ballot(&mut self, v: Ballot) {
+        self.ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ballot(&mut self) -> &mut Ballot {
+        if self.ballot.is_none() {
+            self.ballot.set_default();
+        }
+        self.ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ballot(&mut self) -> Ballot {
+        self.ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+}
+
+impl ::protobuf::Message for CommitOK {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.replica_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.instance_number = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.ok = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ballot)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.replica_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.replica_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.instance_number != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.instance_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ok != false {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.replica_id != 0 {
+            os.write_int32(1, self.replica_id)?;
+        }
+        if self.instance_number != 0 {
+            os.write_int32(2, self.instance_number)?;
+        }
+        if self.ok != false {
+            os.write_bool(3, self.ok)?;
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CommitOK {
+        CommitOK::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "replica_id",
+                    |m: &CommitOK| { &m.replica_id },
+                    |m: &mut CommitOK| { &mut m.replica_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "instance_number",
+                    |m: &CommitOK| { &m.instance_number },
+                    |m: &mut CommitOK| { &mut m.instance_number },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "ok",
+                    |m: &CommitOK| { &m.ok },
+                    |m: &mut CommitOK| { &mut m.ok },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                    "ballot",
+                    |m: &CommitOK| { &m.ballot },
+                    |m: &mut CommitOK| { &mut m.ballot },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CommitOK>(
+                    "CommitOK",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CommitOK {
+        static mut instance: ::protobuf::lazy::Lazy<CommitOK> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CommitOK,
+        };
+        unsafe {
+            instance.get(CommitOK::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CommitOK {
+    fn clear(&mut self) {
+        self.replica_id = 0;
+        self.instance_number = 0;
+        self.ok = false;
+        self.ballot.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CommitOK {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CommitOK {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Empty {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Empty {
+    fn default() -> &'a Empty {
+        <Empty as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Empty {
+    pub fn new() -> Empty {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for Empty {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Empty {
+        Empty::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<Empty>(
+                    "Empty",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Empty {
+        static mut instance: ::protobuf::lazy::Lazy<Empty> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Empty,
+        };
+        unsafe {
+            instance.get(Empty::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Empty {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Empty {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Empty {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Prepare {
+    // message fields
+    pub replica_id: i32,
+    pub instance_number: i32,
+    pub ballot: ::protobuf::SingularPtrField<Ballot>,
+    pub candidate: ::protobuf::SingularPtrField<Command>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Prepare {
+    fn default() -> &'a Prepare {
+        <Prepare as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Prepare {
+    pub fn new() -> Prepare {
+        ::std::default::Default::default()
+    }
+
+    // int32 replica_id = 1;
+
+
+    pub fn get_replica_id(&self) -> i32 {
+        self.replica_id
+    }
+    pub fn clear_replica_id(&mut self) {
+        self.replica_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_replica_id(&mut self, v: i32) {
+        self.replica_id = v;
+    }
+
+    // int32 instance_number = 2;
+
+
+    pub fn get_instance_number(&self) -> i32 {
+        self.instance_number
+    }
+    pub fn clear_instance_number(&mut self) {
+        self.instance_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_instance_number(&mut self, v: i32) {
+        self.instance_number = v;
+    }
+
+    // .epaxos.Ballot ballot = 3;
+
+
+    pub fn get_ballot(&self) -> &Ballot {
+        self.ballot.as_ref().unwrap_or_else(|| Ballot::default_instance())
+    }
+    pub fn clear_ballot(&mut self) {
+        self.ballot.clear();
+    }
+
+    pub fn has_ballot(&self) -> bool {
+        self.ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ballot(&mut self, v: Ballot) {
+        self.ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ballot(&mut self) -> &mut Ballot {
+        if self.ballot.is_none() {
+            self.ballot.set_default();
+        }
+        self.ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ballot(&mut self) -> Ballot {
+        self.ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+
+    // .epaxos.Command candidate = 4;
+
+
+    pub fn get_candidate(&self) -> &Command {
+        self.candidate.as_ref().unwrap_or_else(|| Command::default_instance())
+    }
+    pub fn clear_candidate(&mut self) {
+        self.candidate.clear();
+    }
+
+    pub fn has_candidate(&self) -> bool {
+        self.candidate.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_candidate(&mut self, v: Command) {
+        self.candidate = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_candidate(&mut self) -> &mut Command {
+        if self.candidate.is_none() {
+            self.candidate.set_default();
+        }
+        self.candidate.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_candidate(&mut self) -> Command {
+        self.candidate.take().unwrap_or_else(|| Command::new())
+    }
+}
+
+impl ::protobuf::Message for Prepare {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.candidate {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.replica_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.instance_number = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ballot)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.candidate)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.replica_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.replica_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.instance_number != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.instance_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.candidate.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.replica_id != 0 {
+            os.write_int32(1, self.replica_id)?;
+        }
+        if self.instance_number != 0 {
+            os.write_int32(2, self.instance_number)?;
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.candidate.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Prepare {
+        Prepare::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "replica_id",
+                    |m: &Prepare| { &m.replica_id },
+                    |m: &mut Prepare| { &mut m.replica_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "instance_number",
+                    |m: &Prepare| { &m.instance_number },
+                    |m: &mut Prepare| { &mut m.instance_number },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                    "ballot",
+                    |m: &Prepare| { &m.ballot },
+                    |m: &mut Prepare| { &mut m.ballot },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Command>>(
+                    "candidate",
+                    |m: &Prepare| { &m.candidate },
+                    |m: &mut Prepare| { &mut m.candidate },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Prepare>(
+                    "Prepare",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Prepare {
+        static mut instance: ::protobuf::lazy::Lazy<Prepare> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Prepare,
+        };
+        unsafe {
+            instance.get(Prepare::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Prepare {
+    fn clear(&mut self) {
+        self.replica_id = 0;
+        self.instance_number = 0;
+        self.ballot.clear();
+        self.candidate.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Prepare {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Prepare {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PrepareOK {
+    // message fields
+    pub replica_id: i32,
+    pub instance_number: i32,
+    pub ok: bool,
+    pub ballot: ::protobuf::SingularPtrField<Ballot>,
+    pub command: ::protobuf::SingularPtrField<Command>,
+    pub conflicts: ::protobuf::RepeatedField<Command>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PrepareOK {
+    fn default() -> &'a PrepareOK {
+        <PrepareOK as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PrepareOK {
+    pub fn new() -> PrepareOK {
+        ::std::default::Default::default()
+    }
+
+    // int32 replica_id = 1;
+
+
+    pub fn get_replica_id(&self) -> i32 {
+        self.replica_id
+    }
+    pub fn clear_replica_id(&mut self) {
+        self.replica_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_replica_id(&mut self, v: i32) {
+        self.replica_id = v;
+    }
+
+    // int32 instance_number = 2;
+
+
+    pub fn get_instance_number(&self) -> i32 {
+        self.instance_number
+    }
+    pub fn clear_instance_number(&mut self) {
+        self.instance_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_instance_number(&mut self, v: i32) {
+        self.instance_number = v;
+    }
+
+    // bool ok = 3;
+
+
+    pub fn get_ok(&self) -> bool {
+        self.ok
+    }
+    pub fn clear_ok(&mut self) {
+        self.ok = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ok(&mut self, v: bool) {
+        self.ok = v;
+    }
+
+    // .epaxos.Ballot ballot = 4;
+
+
+    pub fn get_ballot(&self) -> &Ballot {
+        self.ballot.as_ref().unwrap_or_else(|| Ballot::default_instance())
+    }
+    pub fn clear_ballot(&mut self) {
+        self.ballot.clear();
+    }
+
+    pub fn has_ballot(&self) -> bool {
+        self.ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ballot(&mut self, v: Ballot) {
+        self.ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ballot(&mut self) -> &mut Ballot {
+        if self.ballot.is_none() {
+            self.ballot.set_default();
+        }
+        self.ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ballot(&mut self) -> Ballot {
+        self.ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+
+    // .epaxos.Command command = 5;
+
+
+    pub fn get_command(&self) -> &Command {
+        self.command.as_ref().unwrap_or_else(|| Command::default_instance())
+    }
+    pub fn clear_command(&mut self) {
+        self.command.clear();
+    }
+
+    pub fn has_command(&self) -> bool {
+        self.command.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_command(&mut self, v: Command) {
+        self.command = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_command(&mut self) -> &mut Command {
+        if self.command.is_none() {
+            self.command.set_default();
+        }
+        self.command.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_command(&mut self) -> Command {
+        self.command.take().unwrap_or_else(|| Command::new())
+    }
+
+    // repeated .epaxos.Command conflicts = 6;
+
+
+    pub fn get_conflicts(&self) -> &[Command] {
+        &self.conflicts
+    }
+    pub fn clear_conflicts(&mut self) {
+        self.conflicts.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_conflicts(&mut self, v: ::protobuf::RepeatedField<Command>) {
+        self.conflicts = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_conflicts(&mut self) -> &mut ::protobuf::RepeatedField<Command> {
+        &mut self.conflicts
+    }
+
+    // Take field
+    pub fn take_conflicts(&mut self) -> ::protobuf::RepeatedField<Command> {
+        ::std::mem::replace(&mut self.conflicts, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for PrepareOK {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.command {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.conflicts {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.replica_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.instance_number = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.ok = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ballot)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.command)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.conflicts)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.replica_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.replica_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.instance_number != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.instance_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ok != false {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.command.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.conflicts {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.replica_id != 0 {
+            os.write_int32(1, self.replica_id)?;
+        }
+        if self.instance_number != 0 {
+            os.write_int32(2, self.instance_number)?;
+        }
+        if self.ok != false {
+            os.write_bool(3, self.ok)?;
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.command.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.conflicts {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PrepareOK {
+        PrepareOK::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "replica_id",
+                    |m: &PrepareOK| { &m.replica_id },
+                    |m: &mut PrepareOK| { &mut m.replica_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "instance_number",
+                    |m: &PrepareOK| { &m.instance_number },
+                    |m: &mut PrepareOK| { &mut m.instance_number },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "ok",
+                    |m: &PrepareOK| { &m.ok },
+                    |m: &mut PrepareOK| { &mut m.ok },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                    "ballot",
+                    |m: &PrepareOK| { &m.ballot },
+                    |m: &mut PrepareOK| { &mut m.ballot },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Command>>(
+                    "command",
+                    |m: &PrepareOK| { &m.command },
+                    |m: &mut PrepareOK| { &mut m.command },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Command>>(
+                    "conflicts",
+                    |m: &PrepareOK| { &m.conflicts },
+                    |m: &mut PrepareOK| { &mut m.conflicts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PrepareOK>(
+                    "PrepareOK",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PrepareOK {
+        static mut instance: ::protobuf::lazy::Lazy<PrepareOK> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PrepareOK,
+        };
+        unsafe {
+            instance.get(PrepareOK::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PrepareOK {
+    fn clear(&mut self) {
+        self.replica_id = 0;
+        self.instance_number = 0;
+        self.ok = false;
+        self.ballot.clear();
+        self.command.clear();
+        self.conflicts.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PrepareOK {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PrepareOK {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TryPreAccept {
+    // message fields
+    pub replica_id: i32,
+    pub seq: i32,
+    pub deps: ::protobuf::RepeatedField<InstanceRef>,
+    pub instance_number: i32,
+    pub ballot: ::protobuf::SingularPtrField<Ballot>,
+    // message oneof groups
+    pub ClientRequest: ::std::option::Option<TryPreAccept_oneof_ClientRequest>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TryPreAccept {
+    fn default() -> &'a TryPreAccept {
+        <TryPreAccept as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum TryPreAccept_oneof_ClientRequest {
+    write_req(WriteRequest),
+    read_req(ReadRequest),
+}
+
+impl TryPreAccept {
+    pub fn new() -> TryPreAccept {
+        ::std::default::Default::default()
+    }
+
+    // int32 replica_id = 1;
+
+
+    pub fn get_replica_id(&self) -> i32 {
+        self.replica_id
+    }
+    pub fn clear_replica_id(&mut self) {
+        self.replica_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_replica_id(&mut self, v: i32) {
+        self.replica_id = v;
+    }
+
+    // .epaxos.WriteRequest write_req = 2;
+
+
+    pub fn get_write_req(&self) -> &WriteRequest {
+        match self.ClientRequest {
+            ::std::option::Option::Some(TryPreAccept_oneof_ClientRequest::write_req(ref v)) => v,
+            _ => WriteRequest::default_instance(),
+        }
+    }
+    pub fn clear_write_req(&mut self) {
+        self.ClientRequest = ::std::option::Option::None;
+    }
+
+    pub fn has_write_req(&self) -> bool {
+        match self.ClientRequest {
+            ::std::option::Option::Some(TryPreAccept_oneof_ClientRequest::write_req(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_write_req(&mut self, v: WriteRequest) {
+        self.ClientRequest = ::std::option::Option::Some(TryPreAccept_oneof_ClientRequest::write_req(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_write_req(&mut self) -> &mut WriteRequest {
+        if let ::std::option::Option::Some(TryPreAccept_oneof_ClientRequest::write_req(_)) = self.ClientRequest {
+        } else {
+            self.ClientRequest = ::std::option::Option::Some(TryPreAccept_oneof_ClientRequest::write_req(WriteRequest::new()));
+        }
+        match self.ClientRequest {
+            ::std::option::Option::Some(TryPreAccept_oneof_ClientRequest::write_req(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_write_req(&mut self) -> WriteRequest {
+        if self.has_write_req() {
+            match self.ClientRequest.take() {
+                ::std::option::Option::Some(TryPreAccept_oneof_ClientRequest::write_req(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            WriteRequest::new()
+        }
+    }
+
+    // .epaxos.ReadRequest read_req = 3;
+
+
+    pub fn get_read_req(&self) -> &ReadRequest {
+        match self.ClientRequest {
+            ::std::option::Option::Some(TryPreAccept_oneof_ClientRequest::read_req(ref v)) => v,
+            _ => ReadRequest::default_instance(),
+        }
+    }
+    pub fn clear_read_req(&mut self) {
+        self.ClientRequest = ::std::option::Option::None;
+    }
+
+    pub fn has_read_req(&self) -> bool {
+        match self.ClientRequest {
+            ::std::option::Option::Some(TryPreAccept_oneof_ClientRequest::read_req(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_req(&mut self, v: ReadRequest) {
+        self.ClientRequest = ::std::option::Option::Some(TryPreAccept_oneof_ClientRequest::read_req(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_read_req(&mut self) -> &mut ReadRequest {
+        if let ::std::option::Option::Some(TryPreAccept_oneof_ClientRequest::read_req(_)) = self.ClientRequest {
+        } else {
+            self.ClientRequest = ::std::option::Option::Some(TryPreAccept_oneof_ClientRequest::read_req(ReadRequest::new()));
+        }
+        match self.ClientRequest {
+            ::std::option::Option::Some(TryPreAccept_oneof_ClientRequest::read_req(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_read_req(&mut self) -> ReadRequest {
+        if self.has_read_req() {
+            match self.ClientRequest.take() {
+                ::std::option::Option::Some(TryPreAccept_oneof_ClientRequest::read_req(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ReadRequest::new()
+        }
+    }
+
+    // int32 seq = 4;
+
+
+    pub fn get_seq(&self) -> i32 {
+        self.seq
+    }
+    pub fn clear_seq(&mut self) {
+        self.seq = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seq(&mut self, v: i32) {
+        self.seq = v;
+    }
+
+    // repeated .epaxos.InstanceRef deps = 5;
+
+
+    pub fn get_deps(&self) -> &[InstanceRef] {
+        &self.deps
+    }
+    pub fn clear_deps(&mut self) {
+        self.deps.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deps(&mut self, v: ::protobuf::RepeatedField<InstanceRef>) {
+        self.deps = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_deps(&mut self) -> &mut ::protobuf::RepeatedField<InstanceRef> {
+        &mut self.deps
+    }
+
+    // Take field
+    pub fn take_deps(&mut self) -> ::protobuf::RepeatedField<InstanceRef> {
+        ::std::mem::replace(&mut self.deps, ::protobuf::RepeatedField::new())
+    }
+
+    // int32 instance_number = 6;
+
+
+    pub fn get_instance_number(&self) -> i32 {
+        self.instance_number
+    }
+    pub fn clear_instance_number(&mut self) {
+        self.instance_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_instance_number(&mut self, v: i32) {
+        self.instance_number = v;
+    }
+
+    // .epaxos.Ballot ballot = 7;
+
+
+    pub fn get_ballot(&self) -> &Ballot {
+        self.ballot.as_ref().unwrap_or_else(|| Ballot::default_instance())
+    }
+    pub fn clear_ballot(&mut self) {
+        self.ballot.clear();
+    }
+
+    pub fn has_ballot(&self) -> bool {
+        self.ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ballot(&mut self, v: Ballot) {
+        self.ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ballot(&mut self) -> &mut Ballot {
+        if self.ballot.is_none() {
+            self.ballot.set_default();
+        }
+        self.ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ballot(&mut self) -> Ballot {
+        self.ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+}
+
+impl ::protobuf::Message for TryPreAccept {
+    fn is_initialized(&self) -> bool {
+        if let Some(TryPreAccept_oneof_ClientRequest::write_req(ref v)) = self.ClientRequest {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(TryPreAccept_oneof_ClientRequest::read_req(ref v)) = self.ClientRequest {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        for v in &self.deps {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.replica_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.ClientRequest = ::std::option::Option::Some(TryPreAccept_oneof_ClientRequest::write_req(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.ClientRequest = ::std::option::Option::Some(TryPreAccept_oneof_ClientRequest::read_req(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.seq = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.deps)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.instance_number = tmp;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ballot)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.replica_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.replica_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.seq != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.seq, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.deps {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.instance_number != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.instance_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.ClientRequest {
+            match v {
+                &TryPreAccept_oneof_ClientRequest::write_req(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &TryPreAccept_oneof_ClientRequest::read_req(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.replica_id != 0 {
+            os.write_int32(1, self.replica_id)?;
+        }
+        if self.seq != 0 {
+            os.write_int32(4, self.seq)?;
+        }
+        for v in &self.deps {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.instance_number != 0 {
+            os.write_int32(6, self.instance_number)?;
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.ClientRequest {
+            match v {
+                &TryPreAccept_oneof_ClientRequest::write_req(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &TryPreAccept_oneof_ClientRequest::read_req(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TryPreAccept {
+        TryPreAccept::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "replica_id",
+                    |m: &TryPreAccept| { &m.replica_id },
+                    |m: &mut TryPreAccept| { &mut m.replica_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WriteRequest>(
+                    "write_req",
+                    TryPreAccept::has_write_req,
+                    TryPreAccept::get_write_req,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ReadRequest>(
+                    "read_req",
+                    TryPreAccept::has_read_req,
+                    TryPreAccept::get_read_req,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "seq",
+                    |m: &TryPreAccept| { &m.seq },
+                    |m: &mut TryPreAccept| { &mut m.seq },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<InstanceRef>>(
+                    "deps",
+                    |m: &TryPreAccept| { &m.deps },
+                    |m: &mut TryPreAccept| { &mut m.deps },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "instance_number",
+                    |m: &TryPreAccept| { &m.instance_number },
+                    |m: &mut TryPreAccept| { &mut m.instance_number },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                    "ballot",
+                    |m: &TryPreAccept| { &m.ballot },
+                    |m: &mut TryPreAccept| { &mut m.ballot },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TryPreAccept>(
+                    "TryPreAccept",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TryPreAccept {
+        static mut instance: ::protobuf::lazy::Lazy<TryPreAccept> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const TryPreAccept,
+        };
+        unsafe {
+            instance.get(TryPreAccept::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TryPreAccept {
+    fn clear(&mut self) {
+        self.replica_id = 0;
+        self.ClientRequest = ::std::option::Option::None;
+        self.ClientRequest = ::std::option::Option::None;
+        self.seq = 0;
+        self.deps.clear();
+        self.instance_number = 0;
+        self.ballot.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TryPreAccept {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TryPreAccept {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TryPreAcceptOK {
+    // message fields
+    pub replica_id: i32,
+    pub instance_number: i32,
+    pub ok: bool,
+    pub ballot: ::protobuf::SingularPtrField<Ballot>,
+    pub conflict: ::protobuf::SingularPtrField<Command>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TryPreAcceptOK {
+    fn default() -> &'a TryPreAcceptOK {
+        <TryPreAcceptOK as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TryPreAcceptOK {
+    pub fn new() -> TryPreAcceptOK {
+        ::std::default::Default::default()
+    }
+
+    // int32 replica_id = 1;
+
+
+    pub fn get_replica_id(&self) -> i32 {
+        self.replica_id
+    }
+    pub fn clear_replica_id(&mut self) {
+        self.replica_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_replica_id(&mut self, v: i32) {
+        self.replica_id = v;
+    }
+
+    // int32 instance_number = 2;
+
+
+    pub fn get_instance_number(&self) -> i32 {
+        self.instance_number
+    }
+    pub fn clear_instance_number(&mut self) {
+        self.instance_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_instance_number(&mut self, v: i32) {
+        self.instance_number = v;
+    }
+
+    // bool ok = 3;
+
+
+    pub fn get_ok(&self) -> bool {
+        self.ok
+    }
+    pub fn clear_ok(&mut self) {
+        self.ok = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ok(&mut self, v: bool) {
+        self.ok = v;
+    }
+
+    // .epaxos.Ballot ballot = 4;
+
+
+    pub fn get_ballot(&self) -> &Ballot {
+        self.ballot.as_ref().unwrap_or_else(|| Ballot::default_instance())
+    }
+    pub fn clear_ballot(&mut self) {
+        self.ballot.clear();
+    }
+
+    pub fn has_ballot(&self) -> bool {
+        self.ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ballot(&mut self, v: Ballot) {
+        self.ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ballot(&mut self) -> &mut Ballot {
+        if self.ballot.is_none() {
+            self.ballot.set_default();
+        }
+        self.ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ballot(&mut self) -> Ballot {
+        self.ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+
+    // .epaxos.Command conflict = 5;
+
+
+    pub fn get_conflict(&self) -> &Command {
+        self.conflict.as_ref().unwrap_or_else(|| Command::default_instance())
+    }
+    pub fn clear_conflict(&mut self) {
+        self.conflict.clear();
+    }
+
+    pub fn has_conflict(&self) -> bool {
+        self.conflict.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_conflict(&mut self, v: Command) {
+        self.conflict = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_conflict(&mut self) -> &mut Command {
+        if self.conflict.is_none() {
+            self.conflict.set_default();
+        }
+        self.conflict.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_conflict(&mut self) -> Command {
+        self.conflict.take().unwrap_or_else(|| Command::new())
+    }
+}
+
+impl ::protobuf::Message for TryPreAcceptOK {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.conflict {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.replica_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.instance_number = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.ok = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ballot)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.conflict)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.replica_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.replica_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.instance_number != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.instance_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ok != false {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.conflict.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.replica_id != 0 {
+            os.write_int32(1, self.replica_id)?;
+        }
+        if self.instance_number != 0 {
+            os.write_int32(2, self.instance_number)?;
+        }
+        if self.ok != false {
+            os.write_bool(3, self.ok)?;
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.conflict.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TryPreAcceptOK {
+        TryPreAcceptOK::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "replica_id",
+                    |m: &TryPreAcceptOK| { &m.replica_id },
+                    |m: &mut TryPreAcceptOK| { &mut m.replica_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "instance_number",
+                    |m: &TryPreAcceptOK| { &m.instance_number },
+                    |m: &mut TryPreAcceptOK| { &mut m.instance_number },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "ok",
+                    |m: &TryPreAcceptOK| { &m.ok },
+                    |m: &mut TryPreAcceptOK| { &mut m.ok },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                    "ballot",
+                    |m: &TryPreAcceptOK| { &m.ballot },
+                    |m: &mut TryPreAcceptOK| { &mut m.ballot },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Command>>(
+                    "conflict",
+                    |m: &TryPreAcceptOK| { &m.conflict },
+                    |m: &mut TryPreAcceptOK| { &mut m.conflict },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TryPreAcceptOK>(
+                    "TryPreAcceptOK",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TryPreAcceptOK {
+        static mut instance: ::protobuf::lazy::Lazy<TryPreAcceptOK> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const TryPreAcceptOK,
+        };
+        unsafe {
+            instance.get(TryPreAcceptOK::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TryPreAcceptOK {
+    fn clear(&mut self) {
+        self.replica_id = 0;
+        self.instance_number = 0;
+        self.ok = false;
+        self.ballot.clear();
+        self.conflict.clear();
+        self.unknown_fields.clear();
     }
+}
 
-    // Mutable pointer to the field.
-    pub fn mut_read_req(&mut self) -> &mut ReadRequest {
-        if let ::std::option::Option::Some(Commit_oneof_ClientRequest::read_req(_)) = self.ClientRequest {
-        } else {
-            self.ClientRequest = ::std::option::Option::Some(Commit_oneof_ClientRequest::read_req(ReadRequest::new()));
-        }
-        match self.ClientRequest {
-            ::std::option::Option::Some(Commit_oneof_ClientRequest::read_req(ref mut v)) => v,
-            _ => panic!(),
-        }
+impl ::std::fmt::Debug for TryPreAcceptOK {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // Take field
-    pub fn take_read_req(&mut self) -> ReadRequest {
-        if self.has_read_req() {
-            match self.ClientRequest.take() {
-                ::std::option::Option::Some(Commit_oneof_ClientRequest::read_req(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            ReadRequest::new()
-        }
+impl ::protobuf::reflect::ProtobufValue for TryPreAcceptOK {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
+}
 
-    // int32 seq = 4;
-
+#[derive(PartialEq,Clone,Default)]
+pub struct PeerLatency {
+    // message fields
+    pub replica_id: i32,
+    pub rtt_us: i64,
+    pub connected: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
 
-    pub fn get_seq(&self) -> i32 {
-        self.seq
-    }
-    pub fn clear_seq(&mut self) {
-        self.seq = 0;
+impl<'a> ::std::default::Default for &'a PeerLatency {
+    fn default() -> &'a PeerLatency {
+        <PeerLatency as ::protobuf::Message>::default_instance()
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_seq(&mut self, v: i32) {
-        self.seq = v;
+impl PeerLatency {
+    pub fn new() -> PeerLatency {
+        ::std::default::Default::default()
     }
 
-    // repeated .epaxos.Command deps = 5;
+    // int32 replica_id = 1;
 
 
-    pub fn get_deps(&self) -> &[Command] {
-        &self.deps
+    pub fn get_replica_id(&self) -> i32 {
+        self.replica_id
     }
-    pub fn clear_deps(&mut self) {
-        self.deps.clear();
+    pub fn clear_replica_id(&mut self) {
+        self.replica_id = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_deps(&mut self, v: ::protobuf::RepeatedField<Command>) {
-        self.deps = v;
+    pub fn set_replica_id(&mut self, v: i32) {
+        self.replica_id = v;
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_deps(&mut self) -> &mut ::protobuf::RepeatedField<Command> {
-        &mut self.deps
+    // int64 rtt_us = 2;
+
+
+    pub fn get_rtt_us(&self) -> i64 {
+        self.rtt_us
+    }
+    pub fn clear_rtt_us(&mut self) {
+        self.rtt_us = 0;
     }
 
-    // Take field
-    pub fn take_deps(&mut self) -> ::protobuf::RepeatedField<Command> {
-        ::std::mem::replace(&mut self.deps, ::protobuf::RepeatedField::new())
+    // Param is passed by value, moved
+    pub fn set_rtt_us(&mut self, v: i64) {
+        self.rtt_us = v;
     }
 
-    // int32 instance_number = 6;
+    // bool connected = 3;
 
 
-    pub fn get_instance_number(&self) -> i32 {
-        self.instance_number
+    pub fn get_connected(&self) -> bool {
+        self.connected
     }
-    pub fn clear_instance_number(&mut self) {
-        self.instance_number = 0;
+    pub fn clear_connected(&mut self) {
+        self.connected = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_instance_number(&mut self, v: i32) {
-        self.instance_number = v;
+    pub fn set_connected(&mut self, v: bool) {
+        self.connected = v;
     }
 }
 
-impl ::protobuf::Message for Commit {
+impl ::protobuf::Message for PeerLatency {
     fn is_initialized(&self) -> bool {
-        if let Some(Commit_oneof_ClientRequest::write_req(ref v)) = self.ClientRequest {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Commit_oneof_ClientRequest::read_req(ref v)) = self.ClientRequest {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        for v in &self.deps {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -2257,33 +6175,18 @@ impl ::protobuf::Message for Commit {
                     self.replica_id = tmp;
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.ClientRequest = ::std::option::Option::Some(Commit_oneof_ClientRequest::write_req(is.read_message()?));
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.ClientRequest = ::std::option::Option::Some(Commit_oneof_ClientRequest::read_req(is.read_message()?));
-                },
-                4 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int32()?;
-                    self.seq = tmp;
+                    let tmp = is.read_int64()?;
+                    self.rtt_us = tmp;
                 },
-                5 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.deps)?;
-                },
-                6 => {
+                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int32()?;
-                    self.instance_number = tmp;
+                    let tmp = is.read_bool()?;
+                    self.connected = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2300,27 +6203,11 @@ impl ::protobuf::Message for Commit {
         if self.replica_id != 0 {
             my_size += ::protobuf::rt::value_size(1, self.replica_id, ::protobuf::wire_format::WireTypeVarint);
         }
-        if self.seq != 0 {
-            my_size += ::protobuf::rt::value_size(4, self.seq, ::protobuf::wire_format::WireTypeVarint);
-        }
-        for value in &self.deps {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        if self.instance_number != 0 {
-            my_size += ::protobuf::rt::value_size(6, self.instance_number, ::protobuf::wire_format::WireTypeVarint);
+        if self.rtt_us != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.rtt_us, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let ::std::option::Option::Some(ref v) = self.ClientRequest {
-            match v {
-                &Commit_oneof_ClientRequest::write_req(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Commit_oneof_ClientRequest::read_req(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-            };
+        if self.connected != false {
+            my_size += 2;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2331,30 +6218,11 @@ impl ::protobuf::Message for Commit {
         if self.replica_id != 0 {
             os.write_int32(1, self.replica_id)?;
         }
-        if self.seq != 0 {
-            os.write_int32(4, self.seq)?;
-        }
-        for v in &self.deps {
-            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        if self.instance_number != 0 {
-            os.write_int32(6, self.instance_number)?;
+        if self.rtt_us != 0 {
+            os.write_int64(2, self.rtt_us)?;
         }
-        if let ::std::option::Option::Some(ref v) = self.ClientRequest {
-            match v {
-                &Commit_oneof_ClientRequest::write_req(ref v) => {
-                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Commit_oneof_ClientRequest::read_req(ref v) => {
-                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-            };
+        if self.connected != false {
+            os.write_bool(3, self.connected)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2386,8 +6254,8 @@ impl ::protobuf::Message for Commit {
         Self::descriptor_static()
     }
 
-    fn new() -> Commit {
-        Commit::new()
+    fn new() -> PeerLatency {
+        PeerLatency::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2400,36 +6268,21 @@ impl ::protobuf::Message for Commit {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
                     "replica_id",
-                    |m: &Commit| { &m.replica_id },
-                    |m: &mut Commit| { &mut m.replica_id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WriteRequest>(
-                    "write_req",
-                    Commit::has_write_req,
-                    Commit::get_write_req,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ReadRequest>(
-                    "read_req",
-                    Commit::has_read_req,
-                    Commit::get_read_req,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "seq",
-                    |m: &Commit| { &m.seq },
-                    |m: &mut Commit| { &mut m.seq },
+                    |m: &PeerLatency| { &m.replica_id },
+                    |m: &mut PeerLatency| { &mut m.replica_id },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Command>>(
-                    "deps",
-                    |m: &Commit| { &m.deps },
-                    |m: &mut Commit| { &mut m.deps },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "rtt_us",
+                    |m: &PeerLatency| { &m.rtt_us },
+                    |m: &mut PeerLatency| { &mut m.rtt_us },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "instance_number",
-                    |m: &Commit| { &m.instance_number },
-                    |m: &mut Commit| { &mut m.instance_number },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "connected",
+                    |m: &PeerLatency| { &m.connected },
+                    |m: &mut PeerLatency| { &mut m.connected },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Commit>(
-                    "Commit",
+                ::protobuf::reflect::MessageDescriptor::new::<PeerLatency>(
+                    "PeerLatency",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2437,62 +6290,91 @@ impl ::protobuf::Message for Commit {
         }
     }
 
-    fn default_instance() -> &'static Commit {
-        static mut instance: ::protobuf::lazy::Lazy<Commit> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static PeerLatency {
+        static mut instance: ::protobuf::lazy::Lazy<PeerLatency> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Commit,
+            ptr: 0 as *const PeerLatency,
         };
         unsafe {
-            instance.get(Commit::new)
+            instance.get(PeerLatency::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Commit {
+impl ::protobuf::Clear for PeerLatency {
     fn clear(&mut self) {
         self.replica_id = 0;
-        self.ClientRequest = ::std::option::Option::None;
-        self.ClientRequest = ::std::option::Option::None;
-        self.seq = 0;
-        self.deps.clear();
-        self.instance_number = 0;
+        self.rtt_us = 0;
+        self.connected = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Commit {
+impl ::std::fmt::Debug for PeerLatency {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Commit {
+impl ::protobuf::reflect::ProtobufValue for PeerLatency {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Empty {
+pub struct PeerLatencies {
+    // message fields
+    pub peers: ::protobuf::RepeatedField<PeerLatency>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Empty {
-    fn default() -> &'a Empty {
-        <Empty as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a PeerLatencies {
+    fn default() -> &'a PeerLatencies {
+        <PeerLatencies as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Empty {
-    pub fn new() -> Empty {
+impl PeerLatencies {
+    pub fn new() -> PeerLatencies {
         ::std::default::Default::default()
     }
+
+    // repeated .epaxos.PeerLatency peers = 1;
+
+
+    pub fn get_peers(&self) -> &[PeerLatency] {
+        &self.peers
+    }
+    pub fn clear_peers(&mut self) {
+        self.peers.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_peers(&mut self, v: ::protobuf::RepeatedField<PeerLatency>) {
+        self.peers = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_peers(&mut self) -> &mut ::protobuf::RepeatedField<PeerLatency> {
+        &mut self.peers
+    }
+
+    // Take field
+    pub fn take_peers(&mut self) -> ::protobuf::RepeatedField<PeerLatency> {
+        ::std::mem::replace(&mut self.peers, ::protobuf::RepeatedField::new())
+    }
 }
 
-impl ::protobuf::Message for Empty {
+impl ::protobuf::Message for PeerLatencies {
     fn is_initialized(&self) -> bool {
+        for v in &self.peers {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -2500,6 +6382,9 @@ impl ::protobuf::Message for Empty {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.peers)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2512,12 +6397,21 @@ impl ::protobuf::Message for Empty {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        for value in &self.peers {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.peers {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2548,8 +6442,8 @@ impl ::protobuf::Message for Empty {
         Self::descriptor_static()
     }
 
-    fn new() -> Empty {
-        Empty::new()
+    fn new() -> PeerLatencies {
+        PeerLatencies::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2559,9 +6453,14 @@ impl ::protobuf::Message for Empty {
         };
         unsafe {
             descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<Empty>(
-                    "Empty",
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PeerLatency>>(
+                    "peers",
+                    |m: &PeerLatencies| { &m.peers },
+                    |m: &mut PeerLatencies| { &mut m.peers },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PeerLatencies>(
+                    "PeerLatencies",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2569,30 +6468,31 @@ impl ::protobuf::Message for Empty {
         }
     }
 
-    fn default_instance() -> &'static Empty {
-        static mut instance: ::protobuf::lazy::Lazy<Empty> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static PeerLatencies {
+        static mut instance: ::protobuf::lazy::Lazy<PeerLatencies> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Empty,
+            ptr: 0 as *const PeerLatencies,
         };
         unsafe {
-            instance.get(Empty::new)
+            instance.get(PeerLatencies::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Empty {
+impl ::protobuf::Clear for PeerLatencies {
     fn clear(&mut self) {
+        self.peers.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Empty {
+impl ::std::fmt::Debug for PeerLatencies {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Empty {
+impl ::protobuf::reflect::ProtobufValue for PeerLatencies {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -2600,8 +6500,11 @@ impl ::protobuf::reflect::ProtobufValue for Empty {
 
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum State {
-    PRE_ACCEPT = 0,
-    COMMIT = 1,
+    NONE = 0,
+    PRE_ACCEPT = 1,
+    ACCEPTED = 2,
+    COMMIT = 3,
+    EXECUTED = 4,
 }
 
 impl ::protobuf::ProtobufEnum for State {
@@ -2611,16 +6514,22 @@ impl ::protobuf::ProtobufEnum for State {
 
     fn from_i32(value: i32) -> ::std::option::Option<State> {
         match value {
-            0 => ::std::option::Option::Some(State::PRE_ACCEPT),
-            1 => ::std::option::Option::Some(State::COMMIT),
+            0 => ::std::option::Option::Some(State::NONE),
+            1 => ::std::option::Option::Some(State::PRE_ACCEPT),
+            2 => ::std::option::Option::Some(State::ACCEPTED),
+            3 => ::std::option::Option::Some(State::COMMIT),
+            4 => ::std::option::Option::Some(State::EXECUTED),
             _ => ::std::option::Option::None
         }
     }
 
     fn values() -> &'static [Self] {
         static values: &'static [State] = &[
+            State::NONE,
             State::PRE_ACCEPT,
+            State::ACCEPTED,
             State::COMMIT,
+            State::EXECUTED,
         ];
         values
     }
@@ -2643,7 +6552,7 @@ impl ::std::marker::Copy for State {
 
 impl ::std::default::Default for State {
     fn default() -> Self {
-        State::PRE_ACCEPT
+        State::NONE
     }
 }
 
@@ -2658,37 +6567,95 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x05R\x05\
     value\"'\n\rWriteResponse\x12\x16\n\x06commit\x18\x01\x20\x01(\x08R\x06c\
     ommit\"\x1f\n\x0bReadRequest\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\
-    \"$\n\x0cReadResponse\x12\x14\n\x05value\x18\x01\x20\x01(\x05R\x05value\
-    \"\xdd\x01\n\x07Command\x123\n\twrite_req\x18\x01\x20\x01(\x0b2\x14.epax\
-    os.WriteRequestH\0R\x08writeReq\x120\n\x08read_req\x18\x02\x20\x01(\x0b2\
-    \x13.epaxos.ReadRequestH\0R\x07readReq\x12\x10\n\x03seq\x18\x03\x20\x01(\
-    \x05R\x03seq\x12#\n\x04deps\x18\x04\x20\x03(\x0b2\x0f.epaxos.CommandR\
-    \x04deps\x12#\n\x05state\x18\x05\x20\x01(\x0e2\r.epaxos.StateR\x05stateB\
-    \x0f\n\rClientRequest\"\x82\x02\n\tPreAccept\x12\x1d\n\nreplica_id\x18\
+    \":\n\x0cReadResponse\x12\x14\n\x05value\x18\x01\x20\x01(\x05R\x05value\
+    \x12\x14\n\x05found\x18\x02\x20\x01(\x08R\x05found\"U\n\x06Ballot\x12\
+    \x14\n\x05epoch\x18\x01\x20\x01(\x05R\x05epoch\x12\x16\n\x06number\x18\
+    \x02\x20\x01(\x05R\x06number\x12\x1d\n\nreplica_id\x18\x03\x20\x01(\x05R\
+    \treplicaId\"U\n\x0bInstanceRef\x12\x1d\n\nreplica_id\x18\x01\x20\x01(\
+    \x05R\treplicaId\x12'\n\x0finstance_number\x18\x02\x20\x01(\x05R\x0einst\
+    anceNumber\"\x8a\x03\n\x07Command\x123\n\twrite_req\x18\x01\x20\x01(\x0b\
+    2\x14.epaxos.WriteRequestH\0R\x08writeReq\x120\n\x08read_req\x18\x02\x20\
+    \x01(\x0b2\x13.epaxos.ReadRequestH\0R\x07readReq\x12\x10\n\x03seq\x18\
+    \x03\x20\x01(\x05R\x03seq\x12'\n\x04deps\x18\x04\x20\x03(\x0b2\x13.epaxo\
+    s.InstanceRefR\x04deps\x12#\n\x05state\x18\x05\x20\x01(\x0e2\r.epaxos.St\
+    ateR\x05state\x12\x1d\n\nreplica_id\x18\x06\x20\x01(\x05R\treplicaId\x12\
+    '\n\x0finstance_number\x18\x07\x20\x01(\x05R\x0einstanceNumber\x12&\n\
+    \x06ballot\x18\x08\x20\x01(\x0b2\x0e.epaxos.BallotR\x06ballot\x127\n\x0f\
+    accepted_ballot\x18\t\x20\x01(\x0b2\x0e.epaxos.BallotR\x0eacceptedBallot\
+    B\x0f\n\rClientRequest\"\xae\x02\n\tPreAccept\x12\x1d\n\nreplica_id\x18\
     \x01\x20\x01(\x05R\treplicaId\x123\n\twrite_req\x18\x02\x20\x01(\x0b2\
     \x14.epaxos.WriteRequestH\0R\x08writeReq\x120\n\x08read_req\x18\x03\x20\
     \x01(\x0b2\x13.epaxos.ReadRequestH\0R\x07readReq\x12\x10\n\x03seq\x18\
-    \x04\x20\x01(\x05R\x03seq\x12#\n\x04deps\x18\x05\x20\x03(\x0b2\x0f.epaxo\
-    s.CommandR\x04deps\x12'\n\x0finstance_number\x18\x06\x20\x01(\x05R\x0ein\
-    stanceNumberB\x0f\n\rClientRequest\"\x84\x02\n\x0bPreAcceptOK\x12\x1d\n\
-    \nreplica_id\x18\x01\x20\x01(\x05R\treplicaId\x123\n\twrite_req\x18\x02\
-    \x20\x01(\x0b2\x14.epaxos.WriteRequestH\0R\x08writeReq\x120\n\x08read_re\
-    q\x18\x03\x20\x01(\x0b2\x13.epaxos.ReadRequestH\0R\x07readReq\x12\x10\n\
-    \x03seq\x18\x04\x20\x01(\x05R\x03seq\x12#\n\x04deps\x18\x05\x20\x03(\x0b\
-    2\x0f.epaxos.CommandR\x04deps\x12'\n\x0finstance_number\x18\x06\x20\x01(\
-    \x05R\x0einstanceNumberB\x0f\n\rClientRequest\"\xff\x01\n\x06Commit\x12\
-    \x1d\n\nreplica_id\x18\x01\x20\x01(\x05R\treplicaId\x123\n\twrite_req\
-    \x18\x02\x20\x01(\x0b2\x14.epaxos.WriteRequestH\0R\x08writeReq\x120\n\
-    \x08read_req\x18\x03\x20\x01(\x0b2\x13.epaxos.ReadRequestH\0R\x07readReq\
-    \x12\x10\n\x03seq\x18\x04\x20\x01(\x05R\x03seq\x12#\n\x04deps\x18\x05\
-    \x20\x03(\x0b2\x0f.epaxos.CommandR\x04deps\x12'\n\x0finstance_number\x18\
-    \x06\x20\x01(\x05R\x0einstanceNumberB\x0f\n\rClientRequest\"\x07\n\x05Em\
-    pty*#\n\x05State\x12\x0e\n\nPRE_ACCEPT\x10\0\x12\n\n\x06COMMIT\x10\x012\
-    \xd7\x01\n\rEpaxosService\x124\n\x05write\x12\x14.epaxos.WriteRequest\
-    \x1a\x15.epaxos.WriteResponse\x121\n\x04read\x12\x13.epaxos.ReadRequest\
-    \x1a\x14.epaxos.ReadResponse\x124\n\npre_accept\x12\x11.epaxos.PreAccept\
-    \x1a\x13.epaxos.PreAcceptOK\x12'\n\x06commit\x12\x0e.epaxos.Commit\x1a\r\
-    .epaxos.Emptyb\x06proto3\
+    \x04\x20\x01(\x05R\x03seq\x12'\n\x04deps\x18\x05\x20\x03(\x0b2\x13.epaxo\
+    s.InstanceRefR\x04deps\x12'\n\x0finstance_number\x18\x06\x20\x01(\x05R\
+    \x0einstanceNumber\x12&\n\x06ballot\x18\x07\x20\x01(\x0b2\x0e.epaxos.Bal\
+    lotR\x06ballotB\x0f\n\rClientRequest\"\xc0\x02\n\x0bPreAcceptOK\x12\x1d\
+    \n\nreplica_id\x18\x01\x20\x01(\x05R\treplicaId\x123\n\twrite_req\x18\
+    \x02\x20\x01(\x0b2\x14.epaxos.WriteRequestH\0R\x08writeReq\x120\n\x08rea\
+    d_req\x18\x03\x20\x01(\x0b2\x13.epaxos.ReadRequestH\0R\x07readReq\x12\
+    \x10\n\x03seq\x18\x04\x20\x01(\x05R\x03seq\x12'\n\x04deps\x18\x05\x20\
+    \x03(\x0b2\x13.epaxos.InstanceRefR\x04deps\x12'\n\x0finstance_number\x18\
+    \x06\x20\x01(\x05R\x0einstanceNumber\x12&\n\x06ballot\x18\x07\x20\x01(\
+    \x0b2\x0e.epaxos.BallotR\x06ballot\x12\x0e\n\x02ok\x18\x08\x20\x01(\x08R\
+    \x02okB\x0f\n\rClientRequest\"\xab\x02\n\x06Accept\x12\x1d\n\nreplica_id\
+    \x18\x01\x20\x01(\x05R\treplicaId\x123\n\twrite_req\x18\x02\x20\x01(\x0b\
+    2\x14.epaxos.WriteRequestH\0R\x08writeReq\x120\n\x08read_req\x18\x03\x20\
+    \x01(\x0b2\x13.epaxos.ReadRequestH\0R\x07readReq\x12\x10\n\x03seq\x18\
+    \x04\x20\x01(\x05R\x03seq\x12'\n\x04deps\x18\x05\x20\x03(\x0b2\x13.epaxo\
+    s.InstanceRefR\x04deps\x12'\n\x0finstance_number\x18\x06\x20\x01(\x05R\
+    \x0einstanceNumber\x12&\n\x06ballot\x18\x07\x20\x01(\x0b2\x0e.epaxos.Bal\
+    lotR\x06ballotB\x0f\n\rClientRequest\"\x8a\x01\n\x08AcceptOK\x12\x1d\n\n\
+    replica_id\x18\x01\x20\x01(\x05R\treplicaId\x12'\n\x0finstance_number\
+    \x18\x02\x20\x01(\x05R\x0einstanceNumber\x12\x0e\n\x02ok\x18\x03\x20\x01\
+    (\x08R\x02ok\x12&\n\x06ballot\x18\x04\x20\x01(\x0b2\x0e.epaxos.BallotR\
+    \x06ballot\"\xab\x02\n\x06Commit\x12\x1d\n\nreplica_id\x18\x01\x20\x01(\
+    \x05R\treplicaId\x123\n\twrite_req\x18\x02\x20\x01(\x0b2\x14.epaxos.Writ\
+    eRequestH\0R\x08writeReq\x120\n\x08read_req\x18\x03\x20\x01(\x0b2\x13.ep\
+    axos.ReadRequestH\0R\x07readReq\x12\x10\n\x03seq\x18\x04\x20\x01(\x05R\
+    \x03seq\x12'\n\x04deps\x18\x05\x20\x03(\x0b2\x13.epaxos.InstanceRefR\x04\
+    deps\x12'\n\x0finstance_number\x18\x06\x20\x01(\x05R\x0einstanceNumber\
+    \x12&\n\x06ballot\x18\x07\x20\x01(\x0b2\x0e.epaxos.BallotR\x06ballotB\
+    \x0f\n\rClientRequest\"\x8a\x01\n\x08CommitOK\x12\x1d\n\nreplica_id\x18\
+    \x01\x20\x01(\x05R\treplicaId\x12'\n\x0finstance_number\x18\x02\x20\x01(\
+    \x05R\x0einstanceNumber\x12\x0e\n\x02ok\x18\x03\x20\x01(\x08R\x02ok\x12&\
+    \n\x06ballot\x18\x04\x20\x01(\x0b2\x0e.epaxos.BallotR\x06ballot\"\x07\n\
+    \x05Empty\"\xa8\x01\n\x07Prepare\x12\x1d\n\nreplica_id\x18\x01\x20\x01(\
+    \x05R\treplicaId\x12'\n\x0finstance_number\x18\x02\x20\x01(\x05R\x0einst\
+    anceNumber\x12&\n\x06ballot\x18\x03\x20\x01(\x0b2\x0e.epaxos.BallotR\x06\
+    ballot\x12-\n\tcandidate\x18\x04\x20\x01(\x0b2\x0f.epaxos.CommandR\tcand\
+    idate\"\xe5\x01\n\tPrepareOK\x12\x1d\n\nreplica_id\x18\x01\x20\x01(\x05R\
+    \treplicaId\x12'\n\x0finstance_number\x18\x02\x20\x01(\x05R\x0einstanceN\
+    umber\x12\x0e\n\x02ok\x18\x03\x20\x01(\x08R\x02ok\x12&\n\x06ballot\x18\
+    \x04\x20\x01(\x0b2\x0e.epaxos.BallotR\x06ballot\x12)\n\x07command\x18\
+    \x05\x20\x01(\x0b2\x0f.epaxos.CommandR\x07command\x12-\n\tconflicts\x18\
+    \x06\x20\x03(\x0b2\x0f.epaxos.CommandR\tconflicts\"\xb1\x02\n\x0cTryPreA\
+    ccept\x12\x1d\n\nreplica_id\x18\x01\x20\x01(\x05R\treplicaId\x123\n\twri\
+    te_req\x18\x02\x20\x01(\x0b2\x14.epaxos.WriteRequestH\0R\x08writeReq\x12\
+    0\n\x08read_req\x18\x03\x20\x01(\x0b2\x13.epaxos.ReadRequestH\0R\x07read\
+    Req\x12\x10\n\x03seq\x18\x04\x20\x01(\x05R\x03seq\x12'\n\x04deps\x18\x05\
+    \x20\x03(\x0b2\x13.epaxos.InstanceRefR\x04deps\x12'\n\x0finstance_number\
+    \x18\x06\x20\x01(\x05R\x0einstanceNumber\x12&\n\x06ballot\x18\x07\x20\
+    \x01(\x0b2\x0e.epaxos.BallotR\x06ballotB\x0f\n\rClientRequest\"\xbd\x01\
+    \n\x0eTryPreAcceptOK\x12\x1d\n\nreplica_id\x18\x01\x20\x01(\x05R\treplic\
+    aId\x12'\n\x0finstance_number\x18\x02\x20\x01(\x05R\x0einstanceNumber\
+    \x12\x0e\n\x02ok\x18\x03\x20\x01(\x08R\x02ok\x12&\n\x06ballot\x18\x04\
+    \x20\x01(\x0b2\x0e.epaxos.BallotR\x06ballot\x12+\n\x08conflict\x18\x05\
+    \x20\x01(\x0b2\x0f.epaxos.CommandR\x08conflict\"a\n\x0bPeerLatency\x12\
+    \x1d\n\nreplica_id\x18\x01\x20\x01(\x05R\treplicaId\x12\x15\n\x06rtt_us\
+    \x18\x02\x20\x01(\x03R\x05rttUs\x12\x1c\n\tconnected\x18\x03\x20\x01(\
+    \x08R\tconnected\":\n\rPeerLatencies\x12)\n\x05peers\x18\x01\x20\x03(\
+    \x0b2\x13.epaxos.PeerLatencyR\x05peers*I\n\x05State\x12\x08\n\x04NONE\
+    \x10\0\x12\x0e\n\nPRE_ACCEPT\x10\x01\x12\x0c\n\x08ACCEPTED\x10\x02\x12\n\
+    \n\x06COMMIT\x10\x03\x12\x0c\n\x08EXECUTED\x10\x042\xca\x03\n\rEpaxosSer\
+    vice\x124\n\x05write\x12\x14.epaxos.WriteRequest\x1a\x15.epaxos.WriteRes\
+    ponse\x121\n\x04read\x12\x13.epaxos.ReadRequest\x1a\x14.epaxos.ReadRespo\
+    nse\x124\n\npre_accept\x12\x11.epaxos.PreAccept\x1a\x13.epaxos.PreAccept\
+    OK\x12*\n\x06accept\x12\x0e.epaxos.Accept\x1a\x10.epaxos.AcceptOK\x12*\n\
+    \x06commit\x12\x0e.epaxos.Commit\x1a\x10.epaxos.CommitOK\x12-\n\x07prepa\
+    re\x12\x0f.epaxos.Prepare\x1a\x11.epaxos.PrepareOK\x12>\n\x0etry_pre_acc\
+    ept\x12\x14.epaxos.TryPreAccept\x1a\x16.epaxos.TryPreAcceptOK\x12$\n\x04\
+    ping\x12\r.epaxos.Empty\x1a\r.epaxos.Empty\x12-\n\x05peers\x12\r.epaxos.\
+    Empty\x1a\x15.epaxos.PeerLatenciesb\x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
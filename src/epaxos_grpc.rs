@@ -28,7 +28,17 @@ pub trait EpaxosService {
 
     fn pre_accept(&self, o: ::grpc::RequestOptions, p: super::epaxos::PreAccept) -> ::grpc::SingleResponse<super::epaxos::PreAcceptOK>;
 
-    fn commit(&self, o: ::grpc::RequestOptions, p: super::epaxos::Commit) -> ::grpc::SingleResponse<super::epaxos::Empty>;
+    fn accept(&self, o: ::grpc::RequestOptions, p: super::epaxos::Accept) -> ::grpc::SingleResponse<super::epaxos::AcceptOK>;
+
+    fn commit(&self, o: ::grpc::RequestOptions, p: super::epaxos::Commit) -> ::grpc::SingleResponse<super::epaxos::CommitOK>;
+
+    fn prepare(&self, o: ::grpc::RequestOptions, p: super::epaxos::Prepare) -> ::grpc::SingleResponse<super::epaxos::PrepareOK>;
+
+    fn try_pre_accept(&self, o: ::grpc::RequestOptions, p: super::epaxos::TryPreAccept) -> ::grpc::SingleResponse<super::epaxos::TryPreAcceptOK>;
+
+    fn ping(&self, o: ::grpc::RequestOptions, p: super::epaxos::Empty) -> ::grpc::SingleResponse<super::epaxos::Empty>;
+
+    fn peers(&self, o: ::grpc::RequestOptions, p: super::epaxos::Empty) -> ::grpc::SingleResponse<super::epaxos::PeerLatencies>;
 }
 
 // client
@@ -38,7 +48,12 @@ pub struct EpaxosServiceClient {
     method_write: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::epaxos::WriteRequest, super::epaxos::WriteResponse>>,
     method_read: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::epaxos::ReadRequest, super::epaxos::ReadResponse>>,
     method_pre_accept: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::epaxos::PreAccept, super::epaxos::PreAcceptOK>>,
-    method_commit: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::epaxos::Commit, super::epaxos::Empty>>,
+    method_accept: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::epaxos::Accept, super::epaxos::AcceptOK>>,
+    method_commit: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::epaxos::Commit, super::epaxos::CommitOK>>,
+    method_prepare: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::epaxos::Prepare, super::epaxos::PrepareOK>>,
+    method_try_pre_accept: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::epaxos::TryPreAccept, super::epaxos::TryPreAcceptOK>>,
+    method_ping: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::epaxos::Empty, super::epaxos::Empty>>,
+    method_peers: ::std::sync::Arc<::grpc::rt::MethodDescriptor<super::epaxos::Empty, super::epaxos::PeerLatencies>>,
 }
 
 impl ::grpc::ClientStub for EpaxosServiceClient {
@@ -63,12 +78,42 @@ impl ::grpc::ClientStub for EpaxosServiceClient {
                 req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
                 resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
             }),
+            method_accept: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/epaxos.EpaxosService/accept".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
             method_commit: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
                 name: "/epaxos.EpaxosService/commit".to_string(),
                 streaming: ::grpc::rt::GrpcStreaming::Unary,
                 req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
                 resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
             }),
+            method_prepare: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/epaxos.EpaxosService/prepare".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_try_pre_accept: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/epaxos.EpaxosService/try_pre_accept".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_ping: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/epaxos.EpaxosService/ping".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
+            method_peers: ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                name: "/epaxos.EpaxosService/peers".to_string(),
+                streaming: ::grpc::rt::GrpcStreaming::Unary,
+                req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+            }),
         }
     }
 }
@@ -86,9 +131,29 @@ impl EpaxosService for EpaxosServiceClient {
         self.grpc_client.call_unary(o, p, self.method_pre_accept.clone())
     }
 
-    fn commit(&self, o: ::grpc::RequestOptions, p: super::epaxos::Commit) -> ::grpc::SingleResponse<super::epaxos::Empty> {
+    fn accept(&self, o: ::grpc::RequestOptions, p: super::epaxos::Accept) -> ::grpc::SingleResponse<super::epaxos::AcceptOK> {
+        self.grpc_client.call_unary(o, p, self.method_accept.clone())
+    }
+
+    fn commit(&self, o: ::grpc::RequestOptions, p: super::epaxos::Commit) -> ::grpc::SingleResponse<super::epaxos::CommitOK> {
         self.grpc_client.call_unary(o, p, self.method_commit.clone())
     }
+
+    fn prepare(&self, o: ::grpc::RequestOptions, p: super::epaxos::Prepare) -> ::grpc::SingleResponse<super::epaxos::PrepareOK> {
+        self.grpc_client.call_unary(o, p, self.method_prepare.clone())
+    }
+
+    fn try_pre_accept(&self, o: ::grpc::RequestOptions, p: super::epaxos::TryPreAccept) -> ::grpc::SingleResponse<super::epaxos::TryPreAcceptOK> {
+        self.grpc_client.call_unary(o, p, self.method_try_pre_accept.clone())
+    }
+
+    fn ping(&self, o: ::grpc::RequestOptions, p: super::epaxos::Empty) -> ::grpc::SingleResponse<super::epaxos::Empty> {
+        self.grpc_client.call_unary(o, p, self.method_ping.clone())
+    }
+
+    fn peers(&self, o: ::grpc::RequestOptions, p: super::epaxos::Empty) -> ::grpc::SingleResponse<super::epaxos::PeerLatencies> {
+        self.grpc_client.call_unary(o, p, self.method_peers.clone())
+    }
 }
 
 // server
@@ -137,6 +202,18 @@ impl EpaxosServiceServer {
                         ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.pre_accept(o, p))
                     },
                 ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/epaxos.EpaxosService/accept".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.accept(o, p))
+                    },
+                ),
                 ::grpc::rt::ServerMethod::new(
                     ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
                         name: "/epaxos.EpaxosService/commit".to_string(),
@@ -149,6 +226,54 @@ impl EpaxosServiceServer {
                         ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.commit(o, p))
                     },
                 ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/epaxos.EpaxosService/prepare".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.prepare(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/epaxos.EpaxosService/try_pre_accept".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.try_pre_accept(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/epaxos.EpaxosService/ping".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.ping(o, p))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::std::sync::Arc::new(::grpc::rt::MethodDescriptor {
+                        name: "/epaxos.EpaxosService/peers".to_string(),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                        resp_marshaller: Box::new(::grpc::protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |o, p| handler_copy.peers(o, p))
+                    },
+                ),
             ],
         )
     }
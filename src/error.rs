@@ -0,0 +1,46 @@
+// Errors the RPCs fail with. Each one maps to a gRPC status code so callers can
+// tell a bad request from a replica that cannot make progress.
+// A read of a missing key is not an error: ReadResponse.found is false.
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ServiceError {
+    // The request is malformed, retrying it will not help
+    InvalidArgument(String),
+    // Not enough replicas answered to commit the command
+    QuorumUnavailable,
+    // The command committed but did not execute in time
+    Timeout,
+}
+
+impl ServiceError {
+    pub fn status(&self) -> grpc::GrpcStatus {
+        match self {
+            ServiceError::InvalidArgument(_) => grpc::GrpcStatus::Argument,
+            ServiceError::QuorumUnavailable => grpc::GrpcStatus::Unavailable,
+            ServiceError::Timeout => grpc::GrpcStatus::DeadlineExceeded,
+        }
+    }
+}
+
+impl fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ServiceError::InvalidArgument(reason) => write!(f, "invalid argument: {}", reason),
+            ServiceError::QuorumUnavailable => write!(f, "no quorum of replicas available"),
+            ServiceError::Timeout => write!(f, "command did not execute in time"),
+        }
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+impl From<ServiceError> for grpc::Error {
+    fn from(e: ServiceError) -> grpc::Error {
+        grpc::Error::GrpcMessage(grpc::GrpcMessageError {
+            grpc_status: e.status() as i32,
+            grpc_message: e.to_string(),
+        })
+    }
+}
@@ -0,0 +1,261 @@
+// Execution of committed commands, following section 4.3.2 of the EPaxos paper.
+//
+// Committed commands form a dependency graph (an edge goes from a command to
+// each of its deps). A command can only be executed once every command it can
+// reach is committed. We then find the strongly connected components with
+// Tarjan's algorithm, which emits them in inverse topological order, and
+// execute the commands inside each component in increasing seq order.
+
+use crate::epaxos::*;
+use crate::instances::Instances;
+use std::{
+    cmp,
+    collections::{HashMap, HashSet},
+};
+
+pub type InstanceId = (i32, i32);
+
+pub struct Executor<'a> {
+    cmds: &'a mut Instances,
+    store: &'a mut HashMap<String, i32>,
+    // Tarjan bookkeeping
+    index: i32,
+    indices: HashMap<InstanceId, i32>,
+    low_links: HashMap<InstanceId, i32>,
+    stack: Vec<InstanceId>,
+    on_stack: HashSet<InstanceId>,
+    executed: Vec<(InstanceId, Option<i32>)>,
+}
+
+impl<'a> Executor<'a> {
+    pub fn new(cmds: &'a mut Instances, store: &'a mut HashMap<String, i32>) -> Executor<'a> {
+        Executor {
+            cmds,
+            store,
+            index: 0,
+            indices: HashMap::new(),
+            low_links: HashMap::new(),
+            stack: Vec::new(),
+            on_stack: HashSet::new(),
+            executed: Vec::new(),
+        }
+    }
+
+    // Executes every committed command whose dependencies are all committed.
+    // Returns the executed instances in order, with the value each read saw.
+    pub fn execute(mut self) -> Vec<(InstanceId, Option<i32>)> {
+        let mut roots = Vec::new();
+        for cmd in self.cmds.iter() {
+            if cmd.get_state() == State::COMMIT {
+                roots.push((cmd.get_replica_id(), cmd.get_instance_number()));
+            }
+        }
+        let blocked = self.blocked();
+        for root in roots {
+            if self.state_of(root) != Some(State::COMMIT) {
+                // Already executed as part of an earlier component
+                continue;
+            }
+            if blocked.contains(&root) {
+                println!("Instance {:?} is waiting for its dependencies", root);
+                continue;
+            }
+            self.index = 0;
+            self.indices.clear();
+            self.low_links.clear();
+            self.stack.clear();
+            self.on_stack.clear();
+            self.strong_connect(root);
+        }
+        self.executed
+    }
+
+    fn get(&self, id: InstanceId) -> Option<&Command> {
+        self.cmds.get(id.0, id.1)
+    }
+
+    fn state_of(&self, id: InstanceId) -> Option<State> {
+        self.get(id).map(|cmd| cmd.get_state())
+    }
+
+    fn deps_of(&self, id: InstanceId) -> Vec<InstanceId> {
+        match self.get(id) {
+            Some(cmd) => cmd
+                .get_deps()
+                .iter()
+                .map(|dep| (dep.get_replica_id(), dep.get_instance_number()))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    // A command is ready once every command reachable from it is committed. The
+    // ones that are not can reach an instance that is neither committed nor
+    // executed, so we walk the graph backwards from those, once for all roots.
+    fn blocked(&self) -> HashSet<InstanceId> {
+        let mut dependents: HashMap<InstanceId, Vec<InstanceId>> = HashMap::new();
+        let mut blocked = HashSet::new();
+        let mut to_visit = Vec::new();
+        for cmd in self.cmds.iter() {
+            if cmd.get_state() != State::COMMIT {
+                continue;
+            }
+            let id = (cmd.get_replica_id(), cmd.get_instance_number());
+            for dep in self.deps_of(id) {
+                match self.state_of(dep) {
+                    Some(State::EXECUTED) => {}
+                    Some(State::COMMIT) => dependents.entry(dep).or_default().push(id),
+                    _ => {
+                        if blocked.insert(id) {
+                            to_visit.push(id);
+                        }
+                    }
+                }
+            }
+        }
+        while let Some(id) = to_visit.pop() {
+            for dependent in dependents.get(&id).into_iter().flatten() {
+                if blocked.insert(*dependent) {
+                    to_visit.push(*dependent);
+                }
+            }
+        }
+        blocked
+    }
+
+    fn strong_connect(&mut self, id: InstanceId) {
+        self.indices.insert(id, self.index);
+        self.low_links.insert(id, self.index);
+        self.index += 1;
+        self.stack.push(id);
+        self.on_stack.insert(id);
+
+        for dep in self.deps_of(id) {
+            if self.state_of(dep) == Some(State::EXECUTED) {
+                continue;
+            }
+            if !self.indices.contains_key(&dep) {
+                self.strong_connect(dep);
+                let low_link = cmp::min(self.low_links[&id], self.low_links[&dep]);
+                self.low_links.insert(id, low_link);
+            } else if self.on_stack.contains(&dep) {
+                let low_link = cmp::min(self.low_links[&id], self.indices[&dep]);
+                self.low_links.insert(id, low_link);
+            }
+        }
+
+        if self.low_links[&id] == self.indices[&id] {
+            // id is the root of a strongly connected component
+            let mut component = Vec::new();
+            loop {
+                let member = self.stack.pop().unwrap();
+                self.on_stack.remove(&member);
+                component.push(member);
+                if member == id {
+                    break;
+                }
+            }
+            self.execute_component(component);
+        }
+    }
+
+    fn execute_component(&mut self, mut component: Vec<InstanceId>) {
+        // Ties in seq are broken by replica id, then instance number
+        component.sort_by_key(|member| (self.get(*member).unwrap().get_seq(), *member));
+        for member in component {
+            let cmd = self.get(member).unwrap();
+            let mut value = None;
+            if cmd.has_write_req() {
+                let req = cmd.get_write_req().clone();
+                println!(
+                    "Executing instance {:?}: {} = {}",
+                    member,
+                    req.get_key(),
+                    req.get_value()
+                );
+                self.store.insert(req.get_key().to_owned(), req.get_value());
+            } else if cmd.has_read_req() {
+                let key = cmd.get_read_req().get_key();
+                value = self.store.get(key).cloned();
+                println!("Executing instance {:?}: {} is {:?}", member, key, value);
+            }
+            self.cmds
+                .get_mut(member.0, member.1)
+                .set_state(State::EXECUTED);
+            self.executed.push((member, value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deps::instance_ref;
+
+    // A committed write of key = value in replica_id.instance_number
+    fn committed(cmds: &mut Instances, id: InstanceId, seq: i32, deps: &[InstanceId], value: i32) {
+        let cmd = cmds.get_mut(id.0, id.1);
+        let mut write_req = WriteRequest::new();
+        write_req.set_key("x".to_owned());
+        write_req.set_value(value);
+        cmd.set_write_req(write_req);
+        cmd.set_seq(seq);
+        for dep in deps {
+            cmd.mut_deps().push(instance_ref(dep.0, dep.1));
+        }
+        cmd.set_state(State::COMMIT);
+    }
+
+    fn order(executed: &[(InstanceId, Option<i32>)]) -> Vec<InstanceId> {
+        executed.iter().map(|(id, _)| *id).collect()
+    }
+
+    // Instances that depend on each other execute in seq order, whatever order
+    // the graph is walked in
+    #[test]
+    fn a_cycle_executes_in_seq_order() {
+        let mut cmds = Instances::new(3);
+        let mut store = HashMap::new();
+        committed(&mut cmds, (0, 0), 3, &[(1, 0)], 1);
+        committed(&mut cmds, (1, 0), 1, &[(2, 0)], 2);
+        committed(&mut cmds, (2, 0), 2, &[(0, 0)], 3);
+        let executed = Executor::new(&mut cmds, &mut store).execute();
+        assert_eq!(order(&executed), vec![(1, 0), (2, 0), (0, 0)]);
+        assert_eq!(store["x"], 1);
+    }
+
+    // Nothing that can reach an uncommitted instance executes, the rest does
+    #[test]
+    fn a_blocked_dependency_holds_back_its_dependents_only() {
+        let mut cmds = Instances::new(3);
+        let mut store = HashMap::new();
+        committed(&mut cmds, (0, 0), 2, &[(1, 0)], 1);
+        committed(&mut cmds, (1, 0), 3, &[(0, 0), (2, 0)], 2);
+        cmds.get_mut(2, 0).set_state(State::PRE_ACCEPT);
+        committed(&mut cmds, (2, 1), 1, &[], 3);
+        let executed = Executor::new(&mut cmds, &mut store).execute();
+        assert_eq!(order(&executed), vec![(2, 1)]);
+
+        committed(&mut cmds, (2, 0), 1, &[], 4);
+        let executed = Executor::new(&mut cmds, &mut store).execute();
+        assert_eq!(order(&executed), vec![(2, 0), (0, 0), (1, 0)]);
+        assert_eq!(store["x"], 2);
+    }
+
+    // Executed instances stay executed, and later ones only wait for them once
+    #[test]
+    fn executed_instances_do_not_execute_again() {
+        let mut cmds = Instances::new(2);
+        let mut store = HashMap::new();
+        committed(&mut cmds, (0, 0), 1, &[], 1);
+        let executed = Executor::new(&mut cmds, &mut store).execute();
+        assert_eq!(order(&executed), vec![(0, 0)]);
+        assert!(Executor::new(&mut cmds, &mut store).execute().is_empty());
+
+        committed(&mut cmds, (1, 0), 2, &[(0, 0)], 2);
+        let executed = Executor::new(&mut cmds, &mut store).execute();
+        assert_eq!(order(&executed), vec![(1, 0)]);
+        assert_eq!(cmds.get(0, 0).unwrap().get_state(), State::EXECUTED);
+        assert_eq!(store["x"], 2);
+    }
+}
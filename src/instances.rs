@@ -0,0 +1,83 @@
+// Instance spaces, one per replica, keyed by instance number. Messages about an
+// instance can arrive out of order, more than once, or before we have heard of
+// the instances preceding it, so a space is sparse rather than a dense log.
+
+use crate::conflicts::interfere;
+use crate::deps;
+use crate::epaxos::{Command, State};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Default)]
+pub struct Instances {
+    spaces: Vec<BTreeMap<i32, Command>>,
+}
+
+impl Instances {
+    pub fn new(replicas_num: u16) -> Instances {
+        Instances {
+            spaces: (0..replicas_num).map(|_| BTreeMap::new()).collect(),
+        }
+    }
+
+    pub fn get(&self, replica_id: i32, instance_number: i32) -> Option<&Command> {
+        self.spaces
+            .get(replica_id as usize)
+            .and_then(|space| space.get(&instance_number))
+    }
+
+    // Returns the instance, creating it in state NONE if we never heard of it
+    pub fn get_mut(&mut self, replica_id: i32, instance_number: i32) -> &mut Command {
+        self.spaces[replica_id as usize]
+            .entry(instance_number)
+            .or_insert_with(|| {
+                let mut cmd = Command::new();
+                cmd.set_replica_id(replica_id);
+                cmd.set_instance_number(instance_number);
+                cmd.set_state(State::NONE);
+                cmd
+            })
+    }
+
+    // Every known instance, by replica then instance number
+    pub fn iter(&self) -> impl Iterator<Item = &Command> {
+        self.spaces.iter().flat_map(|space| space.values())
+    }
+
+    // Instances committed commands depend on that we know nothing about, e.g.
+    // because they committed while we were down
+    pub fn missing_deps(&self) -> Vec<(i32, i32)> {
+        let mut missing = Vec::new();
+        for cmd in self.iter() {
+            if cmd.get_state() != State::COMMIT {
+                continue;
+            }
+            for dep in cmd.get_deps() {
+                let id = (dep.get_replica_id(), dep.get_instance_number());
+                let known = self
+                    .get(id.0, id.1)
+                    .is_some_and(|dep| dep.get_state() != State::NONE);
+                if !known && !missing.contains(&id) {
+                    missing.push(id);
+                }
+            }
+        }
+        missing
+    }
+
+    // Instances that interfere with cmd but that cmd does not depend on
+    pub fn uncovered_conflicts(&self, cmd: &Command) -> Vec<Command> {
+        self.iter()
+            .filter(|other| {
+                (other.get_replica_id(), other.get_instance_number())
+                    != (cmd.get_replica_id(), cmd.get_instance_number())
+                    && interfere(cmd, other)
+                    && !deps::covers(
+                        cmd.get_deps(),
+                        other.get_replica_id(),
+                        other.get_instance_number(),
+                    )
+            })
+            .cloned()
+            .collect()
+    }
+}
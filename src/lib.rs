@@ -2,6 +2,53 @@ extern crate futures;
 extern crate futures_cpupool;
 extern crate grpc;
 extern crate protobuf;
+extern crate serde;
+extern crate toml;
 
+// Copies the client request, if any, between messages with a ClientRequest oneof
+#[macro_export]
+macro_rules! copy_request {
+    ($from:expr, $to:expr) => {
+        if $from.has_write_req() {
+            $to.set_write_req($from.get_write_req().clone());
+        } else if $from.has_read_req() {
+            $to.set_read_req($from.get_read_req().clone());
+        }
+    };
+}
+
+// The key a message with a ClientRequest oneof touches
+#[macro_export]
+macro_rules! request_key {
+    ($msg:expr) => {
+        if $msg.has_read_req() {
+            $msg.get_read_req().get_key()
+        } else {
+            $msg.get_write_req().get_key()
+        }
+    };
+}
+
+pub mod ballot;
+pub mod config;
+pub mod conflicts;
+pub mod deps;
+// Generated by build.rs from epaxos.proto, for an older compiler than ours
+#[allow(
+    unknown_lints,
+    renamed_and_removed_lints,
+    unused_parens,
+    static_mut_refs,
+    mismatched_lifetime_syntaxes
+)]
 pub mod epaxos;
+#[allow(unknown_lints, renamed_and_removed_lints)]
 pub mod epaxos_grpc;
+pub mod error;
+pub mod execution;
+pub mod instances;
+pub mod peers;
+pub mod quorum;
+pub mod recovery;
+pub mod replica;
+pub mod wal;
@@ -0,0 +1,132 @@
+// Connections to the other replicas. A peer is only connected to when we first
+// need to talk to it, and its connection is dropped when a call to it fails.
+// The next call reconnects, once a backoff that doubles with every consecutive
+// failure has passed, so replicas can be started and restarted in any order.
+//
+// We also keep a smoothed round trip time per peer, as TCP does, so that the
+// closest replicas can be picked for fast quorums.
+
+use crate::config::Config;
+use crate::epaxos_grpc::EpaxosServiceClient;
+use grpc::ClientStub;
+use std::{
+    cmp,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PeerState {
+    // Never contacted, or the last connection was dropped and we may retry now
+    Disconnected,
+    Connected,
+    // The last attempts failed, we do not retry before the given instant
+    Backoff(Instant),
+}
+
+struct Peer {
+    host: String,
+    port: u16,
+    client: Option<Arc<EpaxosServiceClient>>,
+    failures: u32,
+    retry_at: Option<Instant>,
+    rtt: Option<Duration>,
+}
+
+pub struct Peers {
+    peers: Vec<Peer>,
+    min_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Peers {
+    pub fn new(config: &Config) -> Peers {
+        let peers = (0..config.replicas_num())
+            .map(|i| {
+                let replica = config.replica(i as i32).unwrap();
+                Peer {
+                    host: replica.host.clone(),
+                    port: replica.port,
+                    client: None,
+                    failures: 0,
+                    retry_at: None,
+                    rtt: None,
+                }
+            })
+            .collect();
+        Peers {
+            peers,
+            min_backoff: config.rpc_timeout() / 10,
+            max_backoff: config.recovery_timeout(),
+        }
+    }
+
+    // The client for peer i, connecting to it first if needed
+    pub fn client(&mut self, i: u16) -> Result<Arc<EpaxosServiceClient>, grpc::Error> {
+        let peer = &mut self.peers[i as usize];
+        if let Some(client) = &peer.client {
+            return Ok(client.clone());
+        }
+        if let Some(retry_at) = peer.retry_at {
+            if Instant::now() < retry_at {
+                return Err(grpc::Error::Other("peer is backing off"));
+            }
+        }
+        println!("Connecting to replica {} at {}:{}", i, peer.host, peer.port);
+        match grpc::Client::new_plain(&peer.host, peer.port, Default::default()) {
+            Ok(grpc_client) => {
+                let client = Arc::new(EpaxosServiceClient::with_client(Arc::new(grpc_client)));
+                peer.client = Some(client.clone());
+                Ok(client)
+            }
+            Err(e) => {
+                self.failed(i);
+                Err(e)
+            }
+        }
+    }
+
+    // A call to peer i succeeded after rtt
+    pub fn succeeded(&mut self, i: u16, rtt: Duration) {
+        let peer = &mut self.peers[i as usize];
+        peer.failures = 0;
+        peer.retry_at = None;
+        peer.rtt = Some(match peer.rtt {
+            Some(srtt) => (srtt * 7 + rtt) / 8,
+            None => rtt,
+        });
+    }
+
+    pub fn rtt(&self, i: u16) -> Option<Duration> {
+        self.peers[i as usize].rtt
+    }
+
+    // replicas ordered from the closest to the farthest. Unmeasured and
+    // unreachable peers come last, otherwise the given order breaks ties.
+    pub fn by_latency(&self, replicas: &[u16]) -> Vec<u16> {
+        let mut ranked = replicas.to_vec();
+        ranked.sort_by_key(|&i| match (self.state(i), self.rtt(i)) {
+            (PeerState::Backoff(_), _) | (_, None) => (1, Duration::from_secs(0)),
+            (_, Some(rtt)) => (0, rtt),
+        });
+        ranked
+    }
+
+    // A call to peer i failed, its channel may be broken
+    pub fn failed(&mut self, i: u16) {
+        let peer = &mut self.peers[i as usize];
+        peer.client = None;
+        peer.failures += 1;
+        let backoff = self.min_backoff * 2u32.saturating_pow(cmp::min(peer.failures - 1, 16));
+        peer.retry_at = Some(Instant::now() + cmp::min(backoff, self.max_backoff));
+    }
+
+    pub fn state(&self, i: u16) -> PeerState {
+        let peer = &self.peers[i as usize];
+        match (&peer.client, peer.retry_at) {
+            (Some(_), _) => PeerState::Connected,
+            (None, Some(retry_at)) if Instant::now() < retry_at => PeerState::Backoff(retry_at),
+            (None, _) => PeerState::Disconnected,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+// Quorum sizes for a cluster of n = 2F + 1 replicas, counting the command
+// leader itself (section 4.3 of the EPaxos paper).
+
+use std::cmp;
+
+// The number of failures F the cluster survives
+pub fn max_failures(replicas_num: u16) -> u16 {
+    (replicas_num - 1) / 2
+}
+
+// F + floor((F + 1) / 2) replicas must agree for the fast path. A single
+// replica, where F = 0, is a fast quorum on its own.
+pub fn fast_quorum_size(replicas_num: u16) -> u16 {
+    let f = max_failures(replicas_num);
+    cmp::max(f + f.div_ceil(2), 1)
+}
+
+// A simple majority, F + 1, for Paxos-Accept and recovery
+pub fn slow_quorum_size(replicas_num: u16) -> u16 {
+    max_failures(replicas_num) + 1
+}
@@ -0,0 +1,359 @@
+// The decisions recovery takes from the replies to a Prepare, kept apart from
+// the messaging so they can be checked against known schedules.
+//
+// When no reply shows the instance accepted or committed, recovery has to find
+// out whether the command leader may have committed it on the fast path, with
+// attributes some replicas pre-accepted. If it may have, those attributes are
+// the only safe choice. If it cannot have, phase 1 starts over.
+
+use crate::deps;
+use crate::epaxos::{Command, PrepareOK, State};
+use crate::execution::InstanceId;
+use crate::quorum::{fast_quorum_size, max_failures};
+use std::{cmp, collections::HashSet};
+
+#[derive(Debug, PartialEq)]
+pub enum FastPath {
+    // Nothing rules out a fast commit with the candidate attributes
+    Possible,
+    // The candidate attributes cannot have committed on the fast path
+    Impossible,
+    // Interfering instances that are not decided yet could rule it out
+    Blocked(Vec<InstanceId>),
+}
+
+// The attributes most replicas other than the command leader pre-accepted in the
+// initial ballot, and who those replicas are. With F = floor(N/2) of them the
+// attributes may have committed on the fast path. With floor((F+1)/2) of them
+// they still may, which Try-PreAccept finds out.
+pub fn identical_pre_accepts(
+    replicas_num: u16,
+    replica_id: i32,
+    prepare_oks: &[PrepareOK],
+) -> Option<(&Command, Vec<i32>)> {
+    let pre_accepts: Vec<(i32, &Command)> = prepare_oks
+        .iter()
+        .filter(|prepare_ok| prepare_ok.get_replica_id() != replica_id)
+        .map(|prepare_ok| (prepare_ok.get_replica_id(), prepare_ok.get_command()))
+        .filter(|(_, cmd)| initially_pre_accepted(cmd))
+        .collect();
+    let f = max_failures(replicas_num) as usize;
+    pre_accepts
+        .iter()
+        .map(|(_, candidate)| {
+            let agreeing: Vec<i32> = pre_accepts
+                .iter()
+                .filter(|(_, cmd)| same_attributes(cmd, candidate))
+                .map(|(i, _)| *i)
+                .collect();
+            (*candidate, agreeing)
+        })
+        .max_by_key(|(_, agreeing)| agreeing.len())
+        .filter(|(_, agreeing)| agreeing.len() >= cmp::max(f.div_ceil(2), 1))
+}
+
+// The replicas that may be part of a fast quorum for the candidate attributes:
+// the agreeing ones and every replica that did not answer. A command leader that
+// answered is not one of them, since it does not take the fast path after
+// promising a higher ballot.
+pub fn backers(replicas_num: u16, agreeing: &[i32], prepare_oks: &[PrepareOK]) -> Vec<i32> {
+    let answered: HashSet<i32> = prepare_oks
+        .iter()
+        .map(|prepare_ok| prepare_ok.get_replica_id())
+        .collect();
+    (0..replicas_num as i32)
+        .filter(|i| !answered.contains(i) || agreeing.contains(i))
+        .collect()
+}
+
+// The extra check from "EPaxos Revisited" (Tollman et al., NSDI'21): identical
+// PreAcceptOKs alone do not prove that cmd may have committed on the fast path
+// with their attributes. If it did, a fast quorum of backers pre-accepted it
+// before any interfering instance missing from its deps, so such an instance has
+// cmd in its deps once committed. A committed one without cmd proves the fast
+// path was not taken.
+//
+// conflicts are the replies to a Prepare carrying cmd as the candidate, listing
+// every interfering instance the repliers know of that cmd does not depend on.
+// An undecided one blocks the decision, unless its command leader proposed it
+// without cmd: that leader cannot have pre-accepted cmd first, so it does not
+// back cmd.
+//
+// Two recoveries never wait for each other. For both to be blocked, both command
+// leaders must be silent and each instance needs a fast quorum of backers. The
+// answering backers of one exclude the other instance, so the two sets are
+// disjoint. The silent replicas back both, except for the other leader. With at
+// most F of them silent that makes N + F - 2 = 3F - 1 backers at most, fewer
+// than the 2F + 2 * floor((F + 1) / 2) two fast quorums need.
+pub fn may_have_committed_fast(
+    replicas_num: u16,
+    cmd: &Command,
+    backers: &[i32],
+    conflicts: &[PrepareOK],
+) -> FastPath {
+    let fast_quorum = fast_quorum_size(replicas_num) as usize;
+    let id = (cmd.get_replica_id(), cmd.get_instance_number());
+    // The command leader is part of every fast quorum
+    if backers.len() < fast_quorum || !backers.contains(&id.0) {
+        return FastPath::Impossible;
+    }
+    let mut undecided: Vec<InstanceId> = Vec::new();
+    let mut not_backing: HashSet<i32> = HashSet::new();
+    for conflict in conflicts
+        .iter()
+        .flat_map(|prepare_ok| prepare_ok.get_conflicts())
+    {
+        let conflict_id = (conflict.get_replica_id(), conflict.get_instance_number());
+        if conflict_id == id {
+            continue;
+        }
+        let without_cmd = !deps::covers(conflict.get_deps(), id.0, id.1);
+        if committed(conflict) {
+            if without_cmd {
+                return FastPath::Impossible;
+            }
+            continue;
+        }
+        if without_cmd && conflict.get_accepted_ballot().get_number() == 0 {
+            not_backing.insert(conflict_id.0);
+        }
+        if !undecided.contains(&conflict_id) {
+            undecided.push(conflict_id);
+        }
+    }
+    // An instance some replier has seen committed is decided, whatever the others say
+    for conflict in conflicts
+        .iter()
+        .flat_map(|prepare_ok| prepare_ok.get_conflicts())
+        .filter(|conflict| committed(conflict))
+    {
+        let conflict_id = (conflict.get_replica_id(), conflict.get_instance_number());
+        undecided.retain(|id| *id != conflict_id);
+    }
+    if undecided.is_empty() {
+        return FastPath::Possible;
+    }
+    let backing = backers.iter().filter(|i| !not_backing.contains(i)).count();
+    if backing < fast_quorum {
+        FastPath::Impossible
+    } else {
+        undecided.sort();
+        FastPath::Blocked(undecided)
+    }
+}
+
+fn committed(cmd: &Command) -> bool {
+    cmd.get_state() == State::COMMIT || cmd.get_state() == State::EXECUTED
+}
+
+fn initially_pre_accepted(cmd: &Command) -> bool {
+    cmd.get_state() == State::PRE_ACCEPT && cmd.get_accepted_ballot().get_number() == 0
+}
+
+fn same_attributes(a: &Command, b: &Command) -> bool {
+    a.get_seq() == b.get_seq() && a.get_deps() == b.get_deps()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ballot::{initial_ballot, next_ballot};
+    use crate::conflicts::Conflicts;
+    use crate::epaxos::{Commit, PreAccept, Prepare, WriteRequest};
+    use crate::instances::Instances;
+    use crate::replica;
+
+    const N: u16 = 5;
+
+    // The state of one replica, changed by the same handlers the server runs
+    struct Replica {
+        id: i32,
+        cmds: Instances,
+        conflicts: Conflicts,
+    }
+
+    impl Replica {
+        fn new(id: i32) -> Replica {
+            Replica {
+                id,
+                cmds: Instances::new(N),
+                conflicts: Conflicts::new(),
+            }
+        }
+
+        // The command leader starting phase 1 for a write of key
+        fn propose(&mut self, instance_number: i32, key: &str) -> Command {
+            let mut cmd = Command::new();
+            cmd.set_replica_id(self.id);
+            cmd.set_instance_number(instance_number);
+            let mut write_req = WriteRequest::new();
+            write_req.set_key(key.to_owned());
+            cmd.set_write_req(write_req);
+            cmd.set_ballot(initial_ballot(self.id));
+            replica::propose(&mut self.cmds, &mut self.conflicts, &mut cmd);
+            cmd
+        }
+
+        // Handling a PreAccept for cmd, returns what we pre-accepted
+        fn pre_accept(&mut self, cmd: &Command) -> Command {
+            let mut pre_accept_msg = PreAccept::new();
+            pre_accept_msg.set_replica_id(cmd.get_replica_id());
+            pre_accept_msg.set_instance_number(cmd.get_instance_number());
+            pre_accept_msg.set_write_req(cmd.get_write_req().clone());
+            pre_accept_msg.set_seq(cmd.get_seq());
+            pre_accept_msg.set_deps(cmd.get_deps().into());
+            pre_accept_msg.set_ballot(cmd.get_ballot().clone());
+            let (r, _) = replica::pre_accept(
+                self.id,
+                &mut self.cmds,
+                &mut self.conflicts,
+                &pre_accept_msg,
+            );
+            assert!(r.get_ok());
+            self.instance(cmd)
+        }
+
+        fn commit(&mut self, cmd: &Command) {
+            let mut commit_msg = Commit::new();
+            commit_msg.set_replica_id(cmd.get_replica_id());
+            commit_msg.set_instance_number(cmd.get_instance_number());
+            commit_msg.set_write_req(cmd.get_write_req().clone());
+            commit_msg.set_seq(cmd.get_seq());
+            commit_msg.set_deps(cmd.get_deps().into());
+            commit_msg.set_ballot(cmd.get_ballot().clone());
+            replica::commit(self.id, &mut self.cmds, &mut self.conflicts, &commit_msg);
+        }
+
+        fn prepare(&mut self, prepare_msg: &Prepare) -> PrepareOK {
+            let (r, _) = replica::prepare(self.id, &mut self.cmds, prepare_msg);
+            assert!(r.get_ok());
+            r
+        }
+
+        fn instance(&self, cmd: &Command) -> Command {
+            self.cmds
+                .get(cmd.get_replica_id(), cmd.get_instance_number())
+                .unwrap()
+                .clone()
+        }
+    }
+
+    // Runs both Prepare rounds of a recovery of replica_id.instance_number among
+    // the replicas up, led by the first of them with a ballot higher than any they
+    // know of. Returns the candidate, if any, and the verdict on it.
+    fn recover(
+        replicas: &mut [Replica],
+        up: &[usize],
+        replica_id: i32,
+        instance_number: i32,
+    ) -> Option<(Command, FastPath)> {
+        let leader = up[0] as i32;
+        let mut ballot = initial_ballot(leader);
+        for &i in up {
+            if let Some(cmd) = replicas[i].cmds.get(replica_id, instance_number) {
+                if cmd.get_ballot() > &ballot {
+                    ballot = cmd.get_ballot().clone();
+                }
+            }
+        }
+        let mut prepare_msg = Prepare::new();
+        prepare_msg.set_replica_id(replica_id);
+        prepare_msg.set_instance_number(instance_number);
+        prepare_msg.set_ballot(next_ballot(&ballot, leader));
+        let prepare_oks: Vec<PrepareOK> = up
+            .iter()
+            .map(|&i| replicas[i].prepare(&prepare_msg))
+            .collect();
+        let (candidate, agreeing) = identical_pre_accepts(N, replica_id, &prepare_oks)?;
+        let backers = backers(N, &agreeing, &prepare_oks);
+        prepare_msg.set_candidate(candidate.clone());
+        let conflicts: Vec<PrepareOK> = up
+            .iter()
+            .map(|&i| replicas[i].prepare(&prepare_msg))
+            .collect();
+        let fast_path = may_have_committed_fast(N, candidate, &backers, &conflicts);
+        Some((candidate.clone(), fast_path))
+    }
+
+    // Interfering alpha and gamma are proposed at once. alpha commits on the fast
+    // path with replicas 1 and 2 and gamma is pre-accepted by replica 4 only, then
+    // both command leaders crash. Recovering alpha has to wait for gamma, which
+    // replica 4 knows of but not alpha. Recovering gamma must not wait for alpha in
+    // turn, and must not keep gamma's attributes, or alpha and gamma would each
+    // commit without the other.
+    #[test]
+    fn recoveries_of_two_interfering_instances_do_not_wait_for_each_other() {
+        let mut replicas: Vec<Replica> = (0..N as i32).map(Replica::new).collect();
+        let alpha = replicas[0].propose(0, "x");
+        let gamma = replicas[3].propose(0, "x");
+        assert_eq!(replicas[1].pre_accept(&alpha), alpha);
+        assert_eq!(replicas[2].pre_accept(&alpha), alpha);
+        assert_eq!(replicas[4].pre_accept(&gamma), gamma);
+        replicas[0].commit(&alpha);
+        let up = [1, 2, 4];
+
+        let (candidate, fast_path) = recover(&mut replicas, &up, 0, 0).unwrap();
+        assert!(same_attributes(&candidate, &alpha));
+        assert_eq!(fast_path, FastPath::Blocked(vec![(3, 0)]));
+
+        let (candidate, fast_path) = recover(&mut replicas, &up, 3, 0).unwrap();
+        assert!(same_attributes(&candidate, &gamma));
+        assert_eq!(fast_path, FastPath::Impossible);
+
+        // Phase 1 again for gamma, led by replica 1 in the ballot it recovers with,
+        // picks up alpha
+        let mut retried = gamma.clone();
+        retried.set_ballot(replicas[1].instance(&gamma).get_ballot().clone());
+        let gamma = [1, 2, 4].iter().fold(retried, |gamma, &i| {
+            let pre_accepted = replicas[i].pre_accept(&gamma);
+            let mut gamma = gamma.clone();
+            gamma.set_seq(cmp::max(gamma.get_seq(), pre_accepted.get_seq()));
+            deps::union(gamma.mut_deps(), pre_accepted.get_deps());
+            gamma
+        });
+        assert!(deps::covers(gamma.get_deps(), 0, 0));
+        for &i in &[1, 2, 4] {
+            replicas[i].commit(&gamma);
+        }
+
+        // alpha keeps the attributes it committed with on replica 0
+        let (candidate, fast_path) = recover(&mut replicas, &up, 0, 0).unwrap();
+        assert!(same_attributes(&candidate, &alpha));
+        assert_eq!(fast_path, FastPath::Possible);
+    }
+
+    // gamma commits on the fast path with replicas 2 and 4 while only replica 1
+    // pre-accepts alpha. Replicas 2 and 4 never heard of alpha, yet must report
+    // gamma against the candidate.
+    #[test]
+    fn replicas_without_the_instance_report_conflicts_with_the_candidate() {
+        let mut replicas: Vec<Replica> = (0..N as i32).map(Replica::new).collect();
+        let alpha = replicas[0].propose(0, "x");
+        let gamma = replicas[3].propose(0, "x");
+        assert_eq!(replicas[1].pre_accept(&alpha), alpha);
+        for &i in &[2, 4] {
+            assert_eq!(replicas[i].pre_accept(&gamma), gamma);
+        }
+        for &i in &[2, 3, 4] {
+            replicas[i].commit(&gamma);
+        }
+        let (candidate, fast_path) = recover(&mut replicas, &[1, 2, 4], 0, 0).unwrap();
+        assert!(same_attributes(&candidate, &alpha));
+        assert_eq!(fast_path, FastPath::Impossible);
+    }
+
+    // A command leader that answers the Prepare has not taken the fast path, and
+    // will not take it anymore
+    #[test]
+    fn an_answering_command_leader_rules_out_the_fast_path() {
+        let mut replicas: Vec<Replica> = (0..N as i32).map(Replica::new).collect();
+        let alpha = replicas[0].propose(0, "x");
+        for &i in &[1, 2, 3] {
+            assert_eq!(replicas[i].pre_accept(&alpha), alpha);
+        }
+        let (_, fast_path) = recover(&mut replicas, &[0, 1, 2], 0, 0).unwrap();
+        assert_eq!(fast_path, FastPath::Impossible);
+        let (_, fast_path) = recover(&mut replicas, &[1, 2, 3], 0, 0).unwrap();
+        assert_eq!(fast_path, FastPath::Possible);
+    }
+}
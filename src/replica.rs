@@ -0,0 +1,255 @@
+// How protocol messages change the instances of a replica, kept apart from the
+// messaging and the log so that tests can run schedules through them.
+//
+// Callers hold the cmds and conflicts locks for the whole call. Every handler
+// returns its reply and whether the instance changed, in which case the caller
+// logs the new state before anybody sees the reply.
+
+use crate::conflicts::Conflicts;
+use crate::deps;
+use crate::epaxos::{
+    Accept, AcceptOK, Command, Commit, CommitOK, PreAccept, PreAcceptOK, Prepare, PrepareOK, State,
+    TryPreAccept, TryPreAcceptOK,
+};
+use crate::instances::Instances;
+use protobuf::RepeatedField;
+use std::cmp;
+
+// The command leader's own PreAccept: computes cmd's attributes and makes it the
+// state of its instance. With the locks held throughout, of two concurrent
+// interfering commands the second one always sees the first.
+pub fn propose(cmds: &mut Instances, conflicts: &mut Conflicts, cmd: &mut Command) {
+    let (interf, max_seq) = conflicts.find(
+        request_key!(cmd),
+        cmd.has_write_req(),
+        cmd.get_replica_id(),
+        cmd.get_instance_number(),
+    );
+    cmd.set_seq(1 + max_seq);
+    cmd.set_deps(interf);
+    cmd.set_accepted_ballot(cmd.get_ballot().clone());
+    cmd.set_state(State::PRE_ACCEPT);
+    *cmds.get_mut(cmd.get_replica_id(), cmd.get_instance_number()) = cmd.clone();
+    conflicts.add(cmd);
+}
+
+pub fn pre_accept(
+    id: i32,
+    cmds: &mut Instances,
+    conflicts: &mut Conflicts,
+    pre_accept_msg: &PreAccept,
+) -> (PreAcceptOK, bool) {
+    let sending_replica_id = pre_accept_msg.get_replica_id();
+    let i = pre_accept_msg.get_instance_number();
+    let mut r = PreAcceptOK::new();
+    r.set_replica_id(id);
+    r.set_instance_number(i);
+    let cmd = cmds.get_mut(sending_replica_id, i);
+    if pre_accept_msg.get_ballot() < cmd.get_ballot() {
+        println!("Rejecting PreAccept with stale ballot");
+        r.set_ballot(cmd.get_ballot().clone());
+        return (r, false);
+    }
+    r.set_ok(true);
+    if cmd.get_state() != State::NONE
+        && (cmd.get_accepted_ballot() == pre_accept_msg.get_ballot()
+            || cmd.get_state() != State::PRE_ACCEPT)
+    {
+        // A redelivered PreAccept, or one overtaken by a later phase:
+        // answer with what we already have instead of recomputing it
+        println!("Instance {}.{} is already known", sending_replica_id, i);
+        r.set_ballot(cmd.get_ballot().clone());
+        copy_request!(cmd, r);
+        r.set_seq(cmd.get_seq());
+        r.set_deps(RepeatedField::from_vec(cmd.get_deps().to_vec()));
+        return (r, false);
+    }
+    let (interf, max_seq) = conflicts.find(
+        request_key!(pre_accept_msg),
+        pre_accept_msg.has_write_req(),
+        sending_replica_id,
+        i,
+    );
+    let seq = cmp::max(pre_accept_msg.get_seq(), 1 + max_seq);
+    // Union interf with deps
+    let mut deps = RepeatedField::from_vec(pre_accept_msg.get_deps().to_vec());
+    deps::union(&mut deps, &interf);
+    cmd.set_ballot(pre_accept_msg.get_ballot().clone());
+    cmd.set_accepted_ballot(pre_accept_msg.get_ballot().clone());
+    cmd.set_replica_id(sending_replica_id);
+    cmd.set_instance_number(i);
+    copy_request!(pre_accept_msg, cmd);
+    cmd.set_seq(seq);
+    cmd.set_deps(deps.clone());
+    cmd.set_state(State::PRE_ACCEPT);
+    conflicts.add(cmd);
+
+    r.set_ballot(pre_accept_msg.get_ballot().clone());
+    copy_request!(pre_accept_msg, r);
+    r.set_seq(seq);
+    r.set_deps(deps);
+    (r, true)
+}
+
+pub fn accept(
+    id: i32,
+    cmds: &mut Instances,
+    conflicts: &mut Conflicts,
+    accept_msg: &Accept,
+) -> (AcceptOK, bool) {
+    let i = accept_msg.get_instance_number();
+    let mut r = AcceptOK::new();
+    r.set_replica_id(id);
+    r.set_instance_number(i);
+    let cmd = cmds.get_mut(accept_msg.get_replica_id(), i);
+    if accept_msg.get_ballot() < cmd.get_ballot() {
+        println!("Rejecting Accept with stale ballot");
+        r.set_ballot(cmd.get_ballot().clone());
+        return (r, false);
+    }
+    r.set_ok(true);
+    if cmd.get_state() == State::COMMIT || cmd.get_state() == State::EXECUTED {
+        // Committed attributes are final, a late Accept cannot change them
+        r.set_ballot(cmd.get_ballot().clone());
+        return (r, false);
+    }
+    cmd.set_ballot(accept_msg.get_ballot().clone());
+    cmd.set_accepted_ballot(accept_msg.get_ballot().clone());
+    cmd.set_replica_id(accept_msg.get_replica_id());
+    cmd.set_instance_number(i);
+    copy_request!(accept_msg, cmd);
+    cmd.set_seq(accept_msg.get_seq());
+    cmd.set_deps(RepeatedField::from_vec(accept_msg.get_deps().to_vec()));
+    cmd.set_state(State::ACCEPTED);
+    conflicts.add(cmd);
+
+    r.set_ballot(accept_msg.get_ballot().clone());
+    (r, true)
+}
+
+// Adopts the committed attributes, whatever we had for the instance. A committed
+// command is final, so even a Commit from a lower ballot is safe.
+pub fn commit(
+    id: i32,
+    cmds: &mut Instances,
+    conflicts: &mut Conflicts,
+    commit_msg: &Commit,
+) -> (CommitOK, bool) {
+    let mut r = CommitOK::new();
+    r.set_replica_id(id);
+    r.set_instance_number(commit_msg.get_instance_number());
+    r.set_ok(true);
+    let cmd = cmds.get_mut(
+        commit_msg.get_replica_id(),
+        commit_msg.get_instance_number(),
+    );
+    if cmd.get_state() == State::COMMIT || cmd.get_state() == State::EXECUTED {
+        println!(
+            "Instance {}.{} is already committed",
+            commit_msg.get_replica_id(),
+            commit_msg.get_instance_number()
+        );
+        r.set_ballot(cmd.get_ballot().clone());
+        return (r, false);
+    }
+    if commit_msg.get_ballot() > cmd.get_ballot() {
+        cmd.set_ballot(commit_msg.get_ballot().clone());
+    }
+    cmd.clear_write_req();
+    cmd.clear_read_req();
+    copy_request!(commit_msg, cmd);
+    cmd.set_seq(commit_msg.get_seq());
+    cmd.set_deps(RepeatedField::from_vec(commit_msg.get_deps().to_vec()));
+    cmd.set_state(State::COMMIT);
+    conflicts.add(cmd);
+
+    r.set_ballot(commit_msg.get_ballot().clone());
+    (r, true)
+}
+
+pub fn prepare(id: i32, cmds: &mut Instances, prepare_msg: &Prepare) -> (PrepareOK, bool) {
+    let mut r = PrepareOK::new();
+    r.set_replica_id(id);
+    r.set_instance_number(prepare_msg.get_instance_number());
+    let cmd = cmds.get_mut(
+        prepare_msg.get_replica_id(),
+        prepare_msg.get_instance_number(),
+    );
+    if prepare_msg.get_ballot() > cmd.get_ballot() {
+        // Promise not to take part in lower ballots for this instance
+        cmd.set_ballot(prepare_msg.get_ballot().clone());
+        r.set_ok(true);
+        r.set_ballot(cmd.get_accepted_ballot().clone());
+        r.set_command(cmd.clone());
+        (r, true)
+    } else if prepare_msg.has_candidate() && prepare_msg.get_ballot() == cmd.get_ballot() {
+        // What recovery needs to tell whether the candidate may have committed on
+        // the fast path. Our own copy of the instance does not matter, we may not
+        // even have one while knowing of an instance that committed without it.
+        r.set_ok(true);
+        r.set_ballot(cmd.get_accepted_ballot().clone());
+        r.set_command(cmd.clone());
+        let conflicts = cmds.uncovered_conflicts(prepare_msg.get_candidate());
+        r.set_conflicts(RepeatedField::from_vec(conflicts));
+        (r, false)
+    } else {
+        r.set_ballot(cmd.get_ballot().clone());
+        (r, false)
+    }
+}
+
+pub fn try_pre_accept(
+    id: i32,
+    cmds: &mut Instances,
+    conflicts: &mut Conflicts,
+    try_pre_accept_msg: &TryPreAccept,
+) -> (TryPreAcceptOK, bool) {
+    let replica_id = try_pre_accept_msg.get_replica_id();
+    let i = try_pre_accept_msg.get_instance_number();
+    let mut r = TryPreAcceptOK::new();
+    r.set_replica_id(id);
+    r.set_instance_number(i);
+    let mut candidate = Command::new();
+    candidate.set_replica_id(replica_id);
+    candidate.set_instance_number(i);
+    copy_request!(try_pre_accept_msg, candidate);
+    candidate.set_seq(try_pre_accept_msg.get_seq());
+    candidate.set_deps(RepeatedField::from_vec(
+        try_pre_accept_msg.get_deps().to_vec(),
+    ));
+    let cmd = cmds.get_mut(replica_id, i);
+    if try_pre_accept_msg.get_ballot() < cmd.get_ballot() {
+        println!("Rejecting TryPreAccept with stale ballot");
+        r.set_ballot(cmd.get_ballot().clone());
+        return (r, false);
+    }
+    if cmd.get_state() != State::NONE && cmd.get_state() != State::PRE_ACCEPT {
+        // The instance went further than phase 1 here, recovery has to see that
+        r.set_ballot(cmd.get_ballot().clone());
+        r.set_conflict(cmd.clone());
+        return (r, false);
+    }
+    // An interfering instance cmd does not depend on must be ordered after it
+    if let Some(conflict) = cmds
+        .uncovered_conflicts(&candidate)
+        .into_iter()
+        .find(|conflict| {
+            !deps::covers(conflict.get_deps(), replica_id, i)
+                || conflict.get_seq() <= candidate.get_seq()
+        })
+    {
+        r.set_ballot(try_pre_accept_msg.get_ballot().clone());
+        r.set_conflict(conflict);
+        return (r, false);
+    }
+    let cmd = cmds.get_mut(replica_id, i);
+    *cmd = candidate;
+    cmd.set_ballot(try_pre_accept_msg.get_ballot().clone());
+    cmd.set_accepted_ballot(try_pre_accept_msg.get_ballot().clone());
+    cmd.set_state(State::PRE_ACCEPT);
+    conflicts.add(cmd);
+
+    r.set_ok(true);
+    r.set_ballot(try_pre_accept_msg.get_ballot().clone());
+    (r, true)
+}
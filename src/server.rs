@@ -4,159 +4,1063 @@ extern crate futures_cpupool;
 extern crate grpc;
 extern crate protobuf;
 
+use epaxos_rs::ballot::{initial_ballot, next_ballot};
+use epaxos_rs::config::Config;
+use epaxos_rs::conflicts::Conflicts;
+use epaxos_rs::copy_request;
+use epaxos_rs::deps;
 use epaxos_rs::epaxos::*;
 use epaxos_rs::epaxos_grpc::*;
-use grpc::ClientStub;
+use epaxos_rs::error::ServiceError;
+use epaxos_rs::execution::{Executor, InstanceId};
+use epaxos_rs::instances::Instances;
+use epaxos_rs::peers::{PeerState, Peers};
+use epaxos_rs::quorum::{fast_quorum_size, max_failures, slow_quorum_size};
+use epaxos_rs::recovery::{self, FastPath};
+use epaxos_rs::replica;
+use epaxos_rs::wal::Wal;
+use futures01::future::{Either, Future};
+use futures_cpupool::CpuPool;
 use std::{
     cmp,
-    collections::{HashMap, HashSet},
-    env,
-    sync::{Arc, Mutex},
+    collections::{BTreeSet, HashMap, HashSet},
+    env, io, iter, process,
+    sync::{mpsc, Arc, Condvar, Mutex},
     thread,
+    time::{Duration, Instant},
 };
+use tokio_timer::Timer;
 
-pub const QUORUM: u16 = 2;
-pub const REPLICAS_NUM: u16 = 3;
-pub const REPLICA1_PORT: u16 = 10000;
-pub const REPLICA2_PORT: u16 = 10001;
-pub const REPLICA3_PORT: u16 = 10002;
-pub const REPLICA4_PORT: u16 = 10003;
-pub const REPLICA5_PORT: u16 = 10004;
+// A queue of unacknowledged commits per replica. The delivery thread of a
+// replica waits on ready for its queue to fill up.
+struct CommitQueues {
+    id: i32,
+    unacked: Mutex<Vec<HashMap<(i32, i32), Commit>>>,
+    ready: Condvar,
+    delivered: Mutex<Delivered>,
+}
+
+// Our own instances every replica knows to be committed: all of them up to
+// through, and the ones in after
+struct Delivered {
+    through: i32,
+    after: BTreeSet<i32>,
+}
+
+impl CommitQueues {
+    fn new(replicas_num: u16, id: i32, delivered: i32) -> CommitQueues {
+        CommitQueues {
+            id,
+            unacked: Mutex::new(vec![HashMap::new(); replicas_num as usize]),
+            ready: Condvar::new(),
+            delivered: Mutex::new(Delivered {
+                through: delivered,
+                after: BTreeSet::new(),
+            }),
+        }
+    }
+
+    // Queue commit_msg for every replica but skip
+    fn push(&self, commit_msg: &Commit, skip: u16) {
+        let id = (
+            commit_msg.get_replica_id(),
+            commit_msg.get_instance_number(),
+        );
+        let mut unacked = self.unacked.lock().unwrap();
+        for (i, queue) in unacked.iter_mut().enumerate() {
+            if i != skip as usize {
+                queue.insert(id, commit_msg.clone());
+            }
+        }
+        self.ready.notify_all();
+    }
+
+    // The commits replica i has not acknowledged, once there are any
+    fn wait(&self, i: u16) -> Vec<Commit> {
+        let mut unacked = self.unacked.lock().unwrap();
+        while unacked[i as usize].is_empty() {
+            unacked = self.ready.wait(unacked).unwrap();
+        }
+        unacked[i as usize].values().cloned().collect()
+    }
+
+    // Returns how far our instances are delivered when replica i was the last
+    // one to acknowledge id and that moved the mark
+    fn acked(&self, i: u16, id: (i32, i32)) -> Option<i32> {
+        let last = {
+            let mut unacked = self.unacked.lock().unwrap();
+            unacked[i as usize].remove(&id);
+            !unacked.iter().any(|queue| queue.contains_key(&id))
+        };
+        if last && id.0 == self.id {
+            self.delivered(id.1)
+        } else {
+            None
+        }
+    }
+
+    // Our instance needs no Commit from us anymore. Returns the new mark when it moved.
+    fn delivered(&self, instance_number: i32) -> Option<i32> {
+        let delivered = &mut *self.delivered.lock().unwrap();
+        if instance_number <= delivered.through {
+            return None;
+        }
+        delivered.after.insert(instance_number);
+        let before = delivered.through;
+        while delivered.after.remove(&(delivered.through + 1)) {
+            delivered.through += 1;
+        }
+        if delivered.through > before {
+            Some(delivered.through)
+        } else {
+            None
+        }
+    }
+}
 
 #[derive(Clone)]
 struct Epaxos {
     // In grpc, parameters in service are immutable.
     // See https://github.com/stepancheg/grpc-rust/blob/master/docs/FAQ.md
     id: i32,
+    replicas_num: u16,
+    recovery_timeout: Duration,
+    rpc_timeout: Duration,
+    rpc_retries: u32,
+    thrifty: bool,
     store: Arc<Mutex<HashMap<String, i32>>>,
-    cmds: Arc<Mutex<Vec<Vec<Command>>>>, // vectors are growable arrays
+    cmds: Arc<Mutex<Instances>>,
     instance_number: Arc<Mutex<i32>>,
-    replicas: Arc<Mutex<Vec<EpaxosServiceClient>>>,
+    // Our own instances that are still running
+    in_flight: Arc<Mutex<HashSet<i32>>>,
+    // Our own reads waiting for their instance to execute
+    reads: Arc<Mutex<HashMap<i32, mpsc::Sender<Option<i32>>>>>,
+    peers: Arc<Mutex<Peers>>,
+    // Instances we have seen but that did not commit yet, and when
+    pending: Arc<Mutex<HashMap<(i32, i32), Instant>>>,
+    conflicts: Arc<Mutex<Conflicts>>,
+    wal: Arc<Wal>,
+    // Commits each replica has not acknowledged yet. They are retransmitted until
+    // the replica answers, so a lost Commit cannot leave an instance stuck there.
+    unacked_commits: Arc<CommitQueues>,
+    // Runs client requests, so many instances can be in flight at once
+    pool: CpuPool,
+    // Bounds how long we wait for a peer
+    timer: Timer,
 }
 
 impl Epaxos {
-    fn init(id: &i32) -> Epaxos {
-        let mut replicas = Vec::new();
-        let mut cmds = Vec::new();
-        let grpc_replica1 = Arc::new(
-            grpc::Client::new_plain("127.0.0.1", REPLICA1_PORT, Default::default()).unwrap(),
-        );
-        let replica1 = EpaxosServiceClient::with_client(grpc_replica1);
-        let grpc_replica2 = Arc::new(
-            grpc::Client::new_plain("127.0.0.1", REPLICA2_PORT, Default::default()).unwrap(),
-        );
-        let replica2 = EpaxosServiceClient::with_client(grpc_replica2);
-        let grpc_replica3 = Arc::new(
-            grpc::Client::new_plain("127.0.0.1", REPLICA3_PORT, Default::default()).unwrap(),
-        );
-        let replica3 = EpaxosServiceClient::with_client(grpc_replica3);
-        replicas.push(replica1);
-        cmds.push(Vec::new());
-        replicas.push(replica2);
-        cmds.push(Vec::new());
-        replicas.push(replica3);
-        cmds.push(Vec::new());
-        return Epaxos {
+    fn init(id: &i32, config: &Config) -> io::Result<Epaxos> {
+        let mut wal = Wal::open(&config.data_dir, *id)?;
+        let records = wal.replay()?;
+        let delivered = wal.delivered();
+        let epaxos = Epaxos {
             id: *id,
+            replicas_num: config.replicas_num(),
+            recovery_timeout: config.recovery_timeout(),
+            rpc_timeout: config.rpc_timeout(),
+            rpc_retries: config.rpc_retries,
+            thrifty: config.thrifty,
             store: Arc::new(Mutex::new(HashMap::new())),
-            cmds: Arc::new(Mutex::new(cmds)),
+            cmds: Arc::new(Mutex::new(Instances::new(config.replicas_num()))),
             instance_number: Arc::new(Mutex::new(0)),
-            replicas: Arc::new(Mutex::new(replicas)),
+            in_flight: Arc::new(Mutex::new(HashSet::new())),
+            reads: Arc::new(Mutex::new(HashMap::new())),
+            peers: Arc::new(Mutex::new(Peers::new(config))),
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            conflicts: Arc::new(Mutex::new(Conflicts::new())),
+            wal: Arc::new(wal),
+            unacked_commits: Arc::new(CommitQueues::new(config.replicas_num(), *id, delivered)),
+            pool: CpuPool::new(config.max_in_flight),
+            timer: Timer::default(),
+        };
+        epaxos.restore(records);
+        Ok(epaxos)
+    }
+
+    // Rebuild our state from the log after a restart: the instance spaces with
+    // their ballots, the next instance number, the conflict index and, by executing
+    // the committed instances again, the store. Undecided instances are left to
+    // recovery, and our committed ones go to the peers again in case they missed them.
+    fn restore(&self, records: Vec<Command>) {
+        if records.is_empty() {
+            return;
+        }
+        println!("Replaying {} log records", records.len());
+        let restored: Vec<Command> = {
+            let mut cmds = self.cmds.lock().unwrap();
+            for mut record in records {
+                // The store is not in the log, so an instance that executed before
+                // the crash, e.g. one a Prepare logged afterwards, has to execute again
+                if record.get_state() == State::EXECUTED {
+                    record.set_state(State::COMMIT);
+                }
+                let (replica_id, instance_number) =
+                    (record.get_replica_id(), record.get_instance_number());
+                *cmds.get_mut(replica_id, instance_number) = record;
+            }
+            cmds.iter().cloned().collect()
+        };
+        let mut next_instance = 0;
+        for cmd in restored.iter() {
+            let id = (cmd.get_replica_id(), cmd.get_instance_number());
+            if id.0 == self.id {
+                next_instance = cmp::max(next_instance, id.1 + 1);
+            }
+            match cmd.get_state() {
+                // Only a promise was made, there is nothing to recover
+                State::NONE => {}
+                State::PRE_ACCEPT | State::ACCEPTED => {
+                    self.update_conflicts(cmd);
+                    (*self.pending.lock().unwrap()).insert(id, Instant::now());
+                }
+                State::COMMIT | State::EXECUTED => self.update_conflicts(cmd),
+            }
+        }
+        *self.instance_number.lock().unwrap() = next_instance;
+        // Send again the Commits of our instances some replica may not have
+        // acknowledged. Undecided ones get theirs once recovery decides them.
+        let delivered = self.wal.delivered();
+        let mut resent = 0;
+        for instance_number in delivered + 1..next_instance {
+            let cmd = (*self.cmds.lock().unwrap())
+                .get(self.id, instance_number)
+                .cloned();
+            match cmd.as_ref().map(Command::get_state) {
+                Some(State::COMMIT) | Some(State::EXECUTED) => {
+                    self.queue_commit(cmd.as_ref().unwrap());
+                    resent += 1;
+                }
+                Some(State::PRE_ACCEPT) | Some(State::ACCEPTED) => {}
+                // Never proposed before the crash, there is nothing to deliver
+                _ => self.delivered(instance_number),
+            }
+        }
+        println!(
+            "Restored {} instances, next instance is {}, resending {} Commits",
+            restored.len(),
+            next_instance,
+            resent
+        );
+        self.execute();
+    }
+
+    // A command of ours, its instance number is taken once it runs
+    fn new_command(&self) -> Command {
+        let mut cmd = Command::new();
+        cmd.set_replica_id(self.id);
+        cmd.set_ballot(initial_ballot(self.id));
+        cmd
+    }
+
+    // Reads and writes both go through consensus, so reads are linearizable.
+    // Returns the instance cmd committed in, and answers reply once it executed.
+    fn consensus(&self, mut cmd: Command, reply: Option<mpsc::Sender<Option<i32>>>) -> Option<i32> {
+        // The instance number is taken in the same critical section that computes
+        // the attributes and indexes the instance. Otherwise a later instance could
+        // be indexed first, and of two interfering instances neither would depend
+        // on the other.
+        let written = {
+            let mut cmds = self.cmds.lock().unwrap();
+            let mut conflicts = self.conflicts.lock().unwrap();
+            let mut next = self.instance_number.lock().unwrap();
+            cmd.set_instance_number(*next);
+            *next += 1;
+            replica::propose(&mut cmds, &mut conflicts, &mut cmd);
+            self.persist(&cmd)
+        };
+        let instance_number = cmd.get_instance_number();
+        if let Some(reply) = reply {
+            (*self.reads.lock().unwrap()).insert(instance_number, reply);
+        }
+        let in_flight = {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            in_flight.insert(instance_number);
+            in_flight.len()
+        };
+        println!(
+            "Starting consensus for instance {} ({} in flight)",
+            instance_number, in_flight
+        );
+        self.sync(written);
+        let committed = self.pre_accept_round(cmd, true);
+        (*self.in_flight.lock().unwrap()).remove(&instance_number);
+        if !committed {
+            (*self.reads.lock().unwrap()).remove(&instance_number);
+            // Leave the instance to recovery, so it does not block its dependents forever
+            (*self.pending.lock().unwrap()).insert((self.id, instance_number), Instant::now());
+            return None;
+        }
+        Some(instance_number)
+    }
+
+    // Phase 1 for cmd's instance, falling back to Paxos-Accept when the fast path
+    // is not available. Recovery runs this on behalf of other command leaders.
+    fn pre_accept_phase(&self, mut cmd: Command, fast_path: bool) -> bool {
+        let written = {
+            let mut cmds = self.cmds.lock().unwrap();
+            let mut conflicts = self.conflicts.lock().unwrap();
+            let local = cmds.get_mut(cmd.get_replica_id(), cmd.get_instance_number());
+            // Recovery may have taken over the instance, or even committed it
+            if local.get_ballot() > cmd.get_ballot()
+                || local.get_state() == State::COMMIT
+                || local.get_state() == State::EXECUTED
+            {
+                println!(
+                    "Instance has moved on, not pre-accepting {:?}",
+                    cmd.get_ballot()
+                );
+                return false;
+            }
+            replica::propose(&mut cmds, &mut conflicts, &mut cmd);
+            self.persist(&cmd)
         };
+        self.sync(written);
+        self.pre_accept_round(cmd, fast_path)
     }
 
-    // we only need to do consensus for write req
-    fn consensus(&self, write_req: &WriteRequest) {
-        println!("Starting consensus");
+    // Sends the PreAccepts for cmd, which we pre-accepted already, and commits it
+    // on the fast or the slow path
+    fn pre_accept_round(&self, mut cmd: Command, fast_path: bool) -> bool {
+        let (interf, seq) = (
+            protobuf::RepeatedField::from_vec(cmd.get_deps().to_vec()),
+            cmd.get_seq(),
+        );
         let mut pre_accept_msg = PreAccept::new();
-        pre_accept_msg.set_replica_id(self.id);
-        pre_accept_msg.set_instance_number(*self.instance_number.lock().unwrap());
-        pre_accept_msg.set_write_req(write_req.clone());
-        let interf = self.find_interference(write_req.get_key().to_owned());
+        pre_accept_msg.set_replica_id(cmd.get_replica_id());
+        pre_accept_msg.set_instance_number(cmd.get_instance_number());
+        copy_request!(cmd, pre_accept_msg);
         pre_accept_msg.set_deps(interf.clone());
-        let seq = 1 + self.find_max_seq(&interf);
         pre_accept_msg.set_seq(seq);
-        let mut cmd = Command::new();
-        cmd.set_write_req(write_req.clone());
-        cmd.set_seq(seq.clone());
-        cmd.set_deps(interf.clone());
-        cmd.set_state(State::PRE_ACCEPT);
-        (*self.cmds.lock().unwrap())[self.id as usize]
-            .insert(*self.instance_number.lock().unwrap() as usize, cmd);
+        pre_accept_msg.set_ballot(cmd.get_ballot().clone());
         let mut fast_quorum = 0;
-        for i in 0..REPLICAS_NUM {
-            if i == self.id as u16 {
-                continue;
+        let mut pre_accept_oks = Vec::new();
+        let send = |replica: &EpaxosServiceClient, o, msg| replica.pre_accept(o, msg);
+        let (sender, replies) = mpsc::channel();
+        let mut fallback = None;
+        // In thrifty mode only a fast quorum hears from us, unless one of them fails
+        let mut contacted = self.other_replicas();
+        if self.thrifty {
+            let peers_needed = (fast_quorum_size(self.replicas_num) as usize).saturating_sub(1);
+            let rest = contacted.split_off(peers_needed);
+            // Without anyone to wait for, keeping a sender would leave us waiting
+            // forever, e.g. on a single replica
+            if !contacted.is_empty() {
+                fallback = Some((rest, sender.clone()));
             }
-            println!("Sending pre_accept to replica {}", i);
-            let pre_accept_ok = (*self.replicas.lock().unwrap())[i as usize]
-                .pre_accept(grpc::RequestOptions::new(), pre_accept_msg.clone());
-            match pre_accept_ok.wait() {
-                Err(e) => panic!("Replica panic {:?}", e),
-                Ok((_, value, _))
+        }
+        self.send_to(&contacted, pre_accept_msg.clone(), send, &sender);
+        drop(sender);
+        // Stragglers beyond a fast quorum (us included) are ignored
+        for (i, pre_accept_ok) in replies.iter() {
+            match pre_accept_ok {
+                Err(e) => {
+                    println!("Replica {} did not answer the PreAccept: {:?}", i, e);
+                    if let Some((rest, sender)) = fallback.take() {
+                        println!("Falling back to replicas {:?}", rest);
+                        self.send_to(&rest, pre_accept_msg.clone(), send, &sender);
+                    }
+                }
+                Ok(value) if !value.get_ok() => {
+                    println!("Replica {} has a higher ballot {:?}", i, value.get_ballot());
+                    self.adopt_ballot(
+                        cmd.get_replica_id(),
+                        cmd.get_instance_number(),
+                        value.get_ballot(),
+                    );
+                    return false;
+                }
+                Ok(value)
                     if value.get_seq() == pre_accept_msg.get_seq()
                         && value.get_deps() == pre_accept_msg.get_deps() =>
                 {
                     println!("Got an agreeing PreAcceptOK: {:?}", value);
                     fast_quorum += 1;
+                    pre_accept_oks.push(value);
                 }
-                Ok((_, value, _)) => println!("Some dissenting voice here! {:?}", value),
-            }
-        }
-
-        // Commit stage if has quorum
-        if fast_quorum >= QUORUM {
-            // Update the state in the log to commit
-            ((*self.cmds.lock().unwrap())[self.id as usize]
-                [*self.instance_number.lock().unwrap() as usize])
-                .set_state(State::COMMIT);
-
-            // Send Commit message to all replicas
-            let mut commit_msg = Commit::new();
-            commit_msg.set_write_req(write_req.clone());
-            commit_msg.set_seq(seq.clone());
-            commit_msg.set_deps(interf.clone());
-            commit_msg.set_instance_number(*self.instance_number.lock().unwrap());
-            for i in 0..REPLICAS_NUM {
-                if i == self.id as u16 {
-                    continue;
+                Ok(value) => {
+                    println!("Some dissenting voice here! {:?}", value);
+                    pre_accept_oks.push(value);
                 }
-                (*self.replicas.lock().unwrap())[i as usize]
-                    .commit(grpc::RequestOptions::new(), commit_msg.clone());
-                println!("Sending Commit to replica {}", i);
             }
+            if pre_accept_oks.len() + 1 >= fast_quorum_size(self.replicas_num) as usize {
+                break;
+            }
+        }
+
+        // Fast path: a fast quorum (us included) agreed with our attributes
+        if fast_path && fast_quorum + 1 >= fast_quorum_size(self.replicas_num) {
+            return self.send_fast_commit(&cmd);
+        }
+        if pre_accept_oks.len() + 1 < slow_quorum_size(self.replicas_num) as usize {
+            println!("Not enough PreAcceptOKs to go on");
+            return false;
+        }
+
+        // Slow path: take the union of all attributes and run Paxos-Accept
+        let mut seq = seq;
+        let mut deps = interf;
+        for pre_accept_ok in pre_accept_oks.iter() {
+            seq = cmp::max(seq, pre_accept_ok.get_seq());
+            deps::union(&mut deps, pre_accept_ok.get_deps());
+        }
+        cmd.set_seq(seq);
+        cmd.set_deps(deps);
+        let committed = self.paxos_accept(&cmd);
+        if committed {
+            self.send_commit(&cmd);
+        }
+        committed
+    }
+
+    // Sends msg to every other replica at once. Replies come back on the returned
+    // channel in the order they arrive, so callers can stop at a quorum. A replica
+    // that does not answer within the retries shows up as an error, i.e. a missing vote.
+    fn broadcast<Req, Resp, F>(
+        &self,
+        msg: Req,
+        send: F,
+    ) -> mpsc::Receiver<(u16, Result<Resp, grpc::Error>)>
+    where
+        Req: Clone + Send + 'static,
+        Resp: Send + 'static,
+        F: Fn(&EpaxosServiceClient, grpc::RequestOptions, Req) -> grpc::SingleResponse<Resp>
+            + Copy
+            + Send
+            + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        self.send_to(&self.other_replicas(), msg, send, &sender);
+        receiver
+    }
+
+    // Every replica but us, closest first, so that a fast quorum is made of the
+    // replicas that answer soonest. Until we have measured them we start with
+    // the one after us, so that thrifty command leaders spread their load.
+    fn other_replicas(&self) -> Vec<u16> {
+        let replicas: Vec<u16> = (1..self.replicas_num)
+            .map(|offset| (self.id as u16 + offset) % self.replicas_num)
+            .collect();
+        (*self.peers.lock().unwrap()).by_latency(&replicas)
+    }
+
+    // Measure the round trip time to every other replica
+    fn probe_latencies(&self) {
+        let replies = self.broadcast(Empty::new(), |replica, o, msg| replica.ping(o, msg));
+        for (i, reply) in replies.iter() {
+            if let Err(e) = reply {
+                println!("Replica {} did not answer the ping: {:?}", i, e);
+            }
+        }
+    }
+
+    // Sends msg to the given replicas at once, their replies go to sender
+    fn send_to<Req, Resp, F>(
+        &self,
+        replicas: &[u16],
+        msg: Req,
+        send: F,
+        sender: &mpsc::Sender<(u16, Result<Resp, grpc::Error>)>,
+    ) where
+        Req: Clone + Send + 'static,
+        Resp: Send + 'static,
+        F: Fn(&EpaxosServiceClient, grpc::RequestOptions, Req) -> grpc::SingleResponse<Resp>
+            + Copy
+            + Send
+            + 'static,
+    {
+        for &i in replicas {
+            let epaxos = self.clone();
+            let msg = msg.clone();
+            let sender = sender.clone();
+            thread::spawn(move || {
+                let reply = epaxos.call_with_retries(i, msg, send);
+                // The receiver is gone once the caller has heard from a quorum
+                let _ = sender.send((i, reply));
+            });
         }
-        // TODO how to wait for replies w/o blocking
-        *self.instance_number.lock().unwrap() += 1;
     }
 
-    fn find_max_seq(&self, interf: &protobuf::RepeatedField<Command>) -> i32 {
-        let mut seq = 0;
-        for cmd in interf {
-            if cmd.get_seq() > seq {
-                seq = cmd.get_seq();
+    // One RPC to replica i, retried up to rpc_retries times with a deadline on each attempt
+    fn call_with_retries<Req, Resp, F>(
+        &self,
+        i: u16,
+        msg: Req,
+        send: F,
+    ) -> Result<Resp, grpc::Error>
+    where
+        Req: Clone,
+        Resp: Send + 'static,
+        F: Fn(&EpaxosServiceClient, grpc::RequestOptions, Req) -> grpc::SingleResponse<Resp>,
+    {
+        let mut backoff = self.rpc_timeout / 10;
+        let mut attempt = 1;
+        loop {
+            // Do not hold the lock across the call, its outcome is recorded below
+            let client = (*self.peers.lock().unwrap()).client(i);
+            let error = match client {
+                Ok(client) => {
+                    let start = Instant::now();
+                    let response =
+                        send(&client, grpc::RequestOptions::new(), msg.clone()).drop_metadata();
+                    let deadline = self.timer.sleep(self.rpc_timeout);
+                    // Whichever loses the race is dropped, so a call that timed out
+                    // does not keep anything waiting for its answer
+                    let error = match response.select2(deadline).wait() {
+                        Ok(Either::A((value, _))) => {
+                            (*self.peers.lock().unwrap()).succeeded(i, start.elapsed());
+                            return Ok(value);
+                        }
+                        Err(Either::A((e, _))) => e,
+                        Ok(Either::B(_)) | Err(Either::B(_)) => {
+                            grpc::Error::Other("deadline exceeded")
+                        }
+                    };
+                    // The channel may be broken, reconnect on the next attempt
+                    (*self.peers.lock().unwrap()).failed(i);
+                    error
+                }
+                Err(e) => e,
+            };
+            if attempt > self.rpc_retries {
+                return Err(error);
             }
+            println!(
+                "Attempt {} to reach replica {} failed: {:?}, retrying",
+                attempt, i, error
+            );
+            thread::sleep(backoff);
+            backoff *= 2;
+            attempt += 1;
         }
-        return seq;
     }
 
-    fn find_interference(&self, key: String) -> protobuf::RepeatedField<Command> {
-        println!("Finding interf");
-        let mut interf = protobuf::RepeatedField::new();
-        for cmd in (*self.cmds.lock().unwrap()[self.id as usize]).iter() {
-            if cmd.has_write_req() {
-                let req = cmd.get_write_req();
-                if req.key == key {
-                    interf.push(cmd.clone());
+    // Paxos-Accept phase, returns true once a majority (us included) accepted
+    fn paxos_accept(&self, cmd: &Command) -> bool {
+        println!("Starting accept phase");
+        let written = {
+            let mut cmds = self.cmds.lock().unwrap();
+            let local = cmds.get_mut(cmd.get_replica_id(), cmd.get_instance_number());
+            // We promised a higher ballot in the meantime, or the instance got
+            // committed, so our own vote does not count either
+            if local.get_ballot() > cmd.get_ballot()
+                || local.get_state() == State::COMMIT
+                || local.get_state() == State::EXECUTED
+            {
+                println!(
+                    "Instance has moved on, not accepting {:?}",
+                    cmd.get_ballot()
+                );
+                return false;
+            }
+            *local = cmd.clone();
+            local.set_accepted_ballot(cmd.get_ballot().clone());
+            local.set_state(State::ACCEPTED);
+            self.persist(local)
+        };
+        self.sync(written);
+        self.update_conflicts(cmd);
+        let mut accept_msg = Accept::new();
+        accept_msg.set_replica_id(cmd.get_replica_id());
+        accept_msg.set_instance_number(cmd.get_instance_number());
+        copy_request!(cmd, accept_msg);
+        accept_msg.set_seq(cmd.get_seq());
+        accept_msg.set_ballot(cmd.get_ballot().clone());
+        accept_msg.set_deps(protobuf::RepeatedField::from_vec(cmd.get_deps().to_vec()));
+        let mut accept_oks = 1;
+        let replies = self.broadcast(accept_msg, |replica, o, msg| replica.accept(o, msg));
+        for (i, accept_ok) in replies.iter() {
+            match accept_ok {
+                Err(e) => println!("Replica {} did not answer the Accept: {:?}", i, e),
+                Ok(value) if !value.get_ok() => {
+                    println!("Replica {} has a higher ballot {:?}", i, value.get_ballot());
+                    self.adopt_ballot(
+                        cmd.get_replica_id(),
+                        cmd.get_instance_number(),
+                        value.get_ballot(),
+                    );
+                    return false;
                 }
+                Ok(value) => {
+                    println!("Got an AcceptOK: {:?}", value);
+                    accept_oks += 1;
+                }
+            }
+            if accept_oks >= slow_quorum_size(self.replicas_num) {
+                return true;
+            }
+        }
+        accept_oks >= slow_quorum_size(self.replicas_num)
+    }
+
+    fn send_commit(&self, cmd: &Command) {
+        // Update the state in the log to commit
+        let written = {
+            let mut cmds = self.cmds.lock().unwrap();
+            let local = cmds.get_mut(cmd.get_replica_id(), cmd.get_instance_number());
+            // A Commit from whoever else decided the instance may have beaten us to
+            // it. The attributes are the same, and an executed instance must stay so.
+            if local.get_state() == State::COMMIT || local.get_state() == State::EXECUTED {
+                self.wal.written()
             } else {
-                let req = cmd.get_read_req();
-                if req.key == key {
-                    interf.push(cmd.clone());
+                *local = cmd.clone();
+                local.set_state(State::COMMIT);
+                self.persist(local)
+            }
+        };
+        self.sync(written);
+        self.committed(cmd);
+    }
+
+    // Commit cmd on the fast path, unless a replica recovering the instance got a
+    // promise from us first. Recovery takes our answer to mean that we did not
+    // commit, so from then on we must not.
+    fn send_fast_commit(&self, cmd: &Command) -> bool {
+        let written = {
+            let mut cmds = self.cmds.lock().unwrap();
+            let local = cmds.get_mut(cmd.get_replica_id(), cmd.get_instance_number());
+            if local.get_ballot() != cmd.get_ballot() {
+                println!("Instance is being recovered, not taking the fast path");
+                return false;
+            }
+            *local = cmd.clone();
+            local.set_state(State::COMMIT);
+            self.persist(local)
+        };
+        self.sync(written);
+        self.committed(cmd);
+        true
+    }
+
+    fn committed(&self, cmd: &Command) {
+        self.update_conflicts(cmd);
+        (*self.pending.lock().unwrap()).remove(&(cmd.get_replica_id(), cmd.get_instance_number()));
+        self.execute();
+        self.queue_commit(cmd);
+    }
+
+    // Send a Commit for cmd to all replicas
+    fn queue_commit(&self, cmd: &Command) {
+        let mut commit_msg = Commit::new();
+        commit_msg.set_replica_id(cmd.get_replica_id());
+        copy_request!(cmd, commit_msg);
+        commit_msg.set_seq(cmd.get_seq());
+        commit_msg.set_deps(protobuf::RepeatedField::from_vec(cmd.get_deps().to_vec()));
+        commit_msg.set_instance_number(cmd.get_instance_number());
+        commit_msg.set_ballot(cmd.get_ballot().clone());
+        // Nobody waits for the replies, the delivery threads take it from here
+        self.unacked_commits.push(&commit_msg, self.id as u16);
+    }
+
+    // Our instance needs no Commit from us anymore
+    fn delivered(&self, instance_number: i32) {
+        if let Some(through) = self.unacked_commits.delivered(instance_number) {
+            self.record_delivered(through);
+        }
+    }
+
+    // Unlike the log, the mark is only an optimization, so we go on without it
+    fn record_delivered(&self, through: i32) {
+        if let Err(e) = self.wal.set_delivered(through) {
+            println!("Cannot record delivered Commits up to {}: {}", through, e);
+        }
+    }
+
+    // Sends replica i the Commits it has not acknowledged, forever. While the
+    // replica is unreachable we back off, up to the recovery timeout.
+    fn deliver_commits(&self, i: u16) {
+        let mut backoff = self.rpc_timeout;
+        loop {
+            let commits = self.unacked_commits.wait(i);
+            let mut delivered = true;
+            for commit_msg in commits {
+                let id = (
+                    commit_msg.get_replica_id(),
+                    commit_msg.get_instance_number(),
+                );
+                match self
+                    .call_with_retries(i, commit_msg, |replica, o, msg| replica.commit(o, msg))
+                {
+                    Ok(_) => {
+                        if let Some(through) = self.unacked_commits.acked(i, id) {
+                            self.record_delivered(through);
+                        }
+                    }
+                    Err(e) => {
+                        println!(
+                            "Replica {} did not acknowledge the Commit for {}.{}: {:?}",
+                            i, id.0, id.1, e
+                        );
+                        delivered = false;
+                        break;
+                    }
                 }
             }
+            if delivered {
+                backoff = self.rpc_timeout;
+            } else {
+                thread::sleep(backoff);
+                backoff = cmp::min(backoff * 2, self.recovery_timeout);
+            }
         }
-        return interf;
+    }
+
+    // Explicit Prepare: take over an instance whose command leader seems to have failed.
+    // Returns the undecided instances that keep us from deciding this one.
+    fn recover(&self, replica_id: i32, instance_number: i32) -> Vec<InstanceId> {
+        println!(
+            "Replica {} recovering instance {}.{}",
+            self.id, replica_id, instance_number
+        );
+        let ballot = {
+            let mut cmds = self.cmds.lock().unwrap();
+            let cmd = cmds.get_mut(replica_id, instance_number);
+            next_ballot(cmd.get_ballot(), self.id)
+        };
+        let mut prepare_msg = Prepare::new();
+        prepare_msg.set_replica_id(replica_id);
+        prepare_msg.set_instance_number(instance_number);
+        prepare_msg.set_ballot(ballot.clone());
+
+        // Every answer counts when telling whether the fast path was taken, so we
+        // do not stop at a majority
+        let prepare_oks = match self.prepare_round(prepare_msg) {
+            Some(prepare_oks) => prepare_oks,
+            None => return Vec::new(),
+        };
+        if prepare_oks.len() < slow_quorum_size(self.replicas_num) as usize {
+            println!("Not enough PrepareOKs to recover, will retry later");
+            return Vec::new();
+        }
+
+        let known: Vec<&Command> = prepare_oks
+            .iter()
+            .map(|prepare_ok| prepare_ok.get_command())
+            .filter(|cmd| cmd.get_state() != State::NONE)
+            .collect();
+        // A commit is final whatever ballot it came with, a replica may have
+        // learned of it from a Commit without taking part in its ballot
+        let committed = known
+            .iter()
+            .find(|cmd| cmd.get_state() == State::COMMIT || cmd.get_state() == State::EXECUTED)
+            .cloned();
+        // Otherwise only the replies with the highest accepted ballot matter
+        let highest = known.iter().map(|cmd| cmd.get_accepted_ballot()).fold(
+            None,
+            |max: Option<&Ballot>, ballot| match max {
+                Some(max) if max >= ballot => Some(max),
+                _ => Some(ballot),
+            },
+        );
+        let known: Vec<&Command> = known
+            .into_iter()
+            .filter(|cmd| Some(cmd.get_accepted_ballot()) == highest)
+            .collect();
+
+        let mut recovered = Command::new();
+        recovered.set_replica_id(replica_id);
+        recovered.set_instance_number(instance_number);
+        recovered.set_ballot(ballot.clone());
+        let with_attributes = |from: &Command| {
+            let mut cmd = recovered.clone();
+            copy_request!(from, cmd);
+            cmd.set_seq(from.get_seq());
+            cmd.set_deps(protobuf::RepeatedField::from_vec(from.get_deps().to_vec()));
+            cmd
+        };
+
+        if let Some(committed) = committed {
+            println!("Instance is already committed, finishing the commit");
+            self.send_commit(&with_attributes(committed));
+        } else if let Some(accepted) = known.iter().find(|cmd| cmd.get_state() == State::ACCEPTED) {
+            println!("Instance was accepted, running Paxos-Accept again");
+            let cmd = with_attributes(accepted);
+            if self.paxos_accept(&cmd) {
+                self.send_commit(&cmd);
+            }
+        } else if let Some((pre_accepted, agreeing)) =
+            recovery::identical_pre_accepts(self.replicas_num, replica_id, &prepare_oks)
+        {
+            let cmd = with_attributes(pre_accepted);
+            let backers = recovery::backers(self.replicas_num, &agreeing, &prepare_oks);
+            // Ask again, this time for what interferes with the candidate attributes
+            let mut candidate_msg = Prepare::new();
+            candidate_msg.set_replica_id(replica_id);
+            candidate_msg.set_instance_number(instance_number);
+            candidate_msg.set_ballot(ballot);
+            candidate_msg.set_candidate(cmd.clone());
+            let conflicts = match self.prepare_round(candidate_msg) {
+                Some(conflicts) => conflicts,
+                None => return Vec::new(),
+            };
+            let fast_path = match recovery::may_have_committed_fast(
+                self.replicas_num,
+                &cmd,
+                &backers,
+                &conflicts,
+            ) {
+                FastPath::Possible if agreeing.len() < max_failures(self.replicas_num) as usize => {
+                    // Too few to tell, ask the others to pre-accept the same attributes
+                    self.try_pre_accept_phase(&cmd, &agreeing)
+                }
+                fast_path => fast_path,
+            };
+            match fast_path {
+                FastPath::Blocked(blocking) => {
+                    println!("Waiting for interfering instances {:?} to commit", blocking);
+                    return blocking;
+                }
+                FastPath::Possible => {
+                    println!("A majority pre-accepted the same attributes, running Paxos-Accept");
+                    if self.paxos_accept(&cmd) {
+                        self.send_commit(&cmd);
+                    }
+                }
+                FastPath::Impossible => {
+                    println!("Instance cannot have committed on the fast path, restarting phase 1");
+                    let mut cmd = recovered.clone();
+                    copy_request!(pre_accepted, cmd);
+                    self.pre_accept_phase(cmd, false);
+                }
+            }
+        } else if let Some(pre_accepted) = known.first() {
+            println!("Instance was pre-accepted, restarting phase 1");
+            let mut cmd = recovered.clone();
+            copy_request!(pre_accepted, cmd);
+            self.pre_accept_phase(cmd, false);
+        } else {
+            println!("Nobody knows about the instance, committing a no-op");
+            self.pre_accept_phase(recovered.clone(), false);
+        }
+        Vec::new()
+    }
+
+    // Sends prepare_msg to every replica, us included, and waits for all of them.
+    // Returns None if some replica has a higher ballot.
+    fn prepare_round(&self, prepare_msg: Prepare) -> Option<Vec<PrepareOK>> {
+        let replies = self.broadcast(prepare_msg.clone(), |replica, o, msg| {
+            replica.prepare(o, msg)
+        });
+        let local = self
+            .prepare(grpc::RequestOptions::new(), prepare_msg.clone())
+            .wait()
+            .map(|(_, value, _)| value);
+        let mut prepare_oks = Vec::new();
+        for (i, prepare_ok) in vec![(self.id as u16, local)]
+            .into_iter()
+            .chain(replies.iter())
+        {
+            match prepare_ok {
+                Err(e) => println!("Replica {} did not answer the Prepare: {:?}", i, e),
+                Ok(value) if !value.get_ok() => {
+                    println!("Replica {} has a higher ballot {:?}", i, value.get_ballot());
+                    self.adopt_ballot(
+                        prepare_msg.get_replica_id(),
+                        prepare_msg.get_instance_number(),
+                        value.get_ballot(),
+                    );
+                    return None;
+                }
+                Ok(value) => prepare_oks.push(value),
+            }
+        }
+        Some(prepare_oks)
+    }
+
+    // Try-PreAccept: ask the replicas that did not pre-accept cmd's attributes to do
+    // so, unless they know of an interfering instance that could have committed
+    // without cmd. Possible once F replicas other than the command leader
+    // pre-accepted them, Impossible if a committed instance rules the fast path
+    // out, and Blocked by the undecided ones otherwise.
+    fn try_pre_accept_phase(&self, cmd: &Command, agreeing: &[i32]) -> FastPath {
+        println!("Starting Try-PreAccept with {:?} agreeing", agreeing);
+        let mut try_pre_accept_msg = TryPreAccept::new();
+        try_pre_accept_msg.set_replica_id(cmd.get_replica_id());
+        try_pre_accept_msg.set_instance_number(cmd.get_instance_number());
+        copy_request!(cmd, try_pre_accept_msg);
+        try_pre_accept_msg.set_seq(cmd.get_seq());
+        try_pre_accept_msg.set_deps(protobuf::RepeatedField::from_vec(cmd.get_deps().to_vec()));
+        try_pre_accept_msg.set_ballot(cmd.get_ballot().clone());
+
+        let (sender, replies) = mpsc::channel();
+        let others: Vec<u16> = self
+            .other_replicas()
+            .into_iter()
+            .filter(|&i| !agreeing.contains(&(i as i32)))
+            .collect();
+        let send = |replica: &EpaxosServiceClient, o, msg| replica.try_pre_accept(o, msg);
+        self.send_to(&others, try_pre_accept_msg.clone(), send, &sender);
+        if !agreeing.contains(&self.id) {
+            let local = self
+                .try_pre_accept(grpc::RequestOptions::new(), try_pre_accept_msg)
+                .wait()
+                .map(|(_, value, _)| value);
+            let _ = sender.send((self.id as u16, local));
+        }
+        drop(sender);
+
+        let mut pre_accepted = agreeing.len();
+        let mut undecided = Vec::new();
+        // The instance itself went further than phase 1 somewhere, the next Prepare sees it
+        let mut moved_on = false;
+        for (i, try_pre_accept_ok) in replies.iter() {
+            match try_pre_accept_ok {
+                Err(e) => println!("Replica {} did not answer the TryPreAccept: {:?}", i, e),
+                Ok(value) if value.get_ok() => {
+                    if value.get_replica_id() != cmd.get_replica_id() {
+                        pre_accepted += 1;
+                    }
+                }
+                Ok(value) if value.has_conflict() => {
+                    let conflict = value.get_conflict();
+                    println!(
+                        "Replica {} has the interfering instance {}.{}",
+                        i,
+                        conflict.get_replica_id(),
+                        conflict.get_instance_number()
+                    );
+                    let own = (conflict.get_replica_id(), conflict.get_instance_number())
+                        == (cmd.get_replica_id(), cmd.get_instance_number());
+                    match conflict.get_state() {
+                        State::COMMIT | State::EXECUTED if !own => return FastPath::Impossible,
+                        _ if own => moved_on = true,
+                        _ => undecided
+                            .push((conflict.get_replica_id(), conflict.get_instance_number())),
+                    }
+                }
+                Ok(value) => {
+                    println!("Replica {} has a higher ballot {:?}", i, value.get_ballot());
+                    self.adopt_ballot(
+                        cmd.get_replica_id(),
+                        cmd.get_instance_number(),
+                        value.get_ballot(),
+                    );
+                    return FastPath::Blocked(Vec::new());
+                }
+            }
+            if pre_accepted >= max_failures(self.replicas_num) as usize {
+                return FastPath::Possible;
+            }
+        }
+        if undecided.is_empty() && !moved_on {
+            FastPath::Impossible
+        } else {
+            FastPath::Blocked(undecided)
+        }
+    }
+
+    // A replica refused us for a higher ballot. Taking it over makes the next
+    // recovery of the instance pick a ballot above it, instead of running into
+    // the same refusal again.
+    fn adopt_ballot(&self, replica_id: i32, instance_number: i32, ballot: &Ballot) {
+        let mut cmds = self.cmds.lock().unwrap();
+        let cmd = cmds.get_mut(replica_id, instance_number);
+        if ballot > cmd.get_ballot() {
+            cmd.set_ballot(ballot.clone());
+        }
+    }
+
+    // Start recovery for every instance that has not committed in time
+    fn recover_stalled(&self) {
+        // Catch up on instances we depend on but never heard of
+        let missing = (*self.cmds.lock().unwrap()).missing_deps();
+        {
+            let mut pending = self.pending.lock().unwrap();
+            for id in missing {
+                pending.entry(id).or_insert_with(Instant::now);
+            }
+        }
+        let stalled: Vec<(i32, i32)> = (*self.pending.lock().unwrap())
+            .iter()
+            .filter(|(_, seen)| seen.elapsed() > self.recovery_timeout)
+            .map(|(instance, _)| *instance)
+            .collect();
+        let mut recovered = HashSet::new();
+        for (replica_id, instance_number) in stalled {
+            // Back off before trying the same instance again
+            (*self.pending.lock().unwrap()).insert((replica_id, instance_number), Instant::now());
+            recovered.insert((replica_id, instance_number));
+            let blocking = self.recover(replica_id, instance_number);
+            // Their command leaders may be gone too. Decide them now instead of
+            // waiting for them to stall here, then try again.
+            let mut unblocked = false;
+            for (blocking_replica, blocking_instance) in blocking {
+                if recovered.insert((blocking_replica, blocking_instance)) {
+                    self.recover(blocking_replica, blocking_instance);
+                    unblocked = true;
+                }
+            }
+            if unblocked {
+                self.recover(replica_id, instance_number);
+            }
+        }
+    }
+
+    // Apply every committed command whose dependencies are all committed to our
+    // store. Every replica does this, for its own instances and everybody else's.
+    fn execute(&self) {
+        let mut cmds = self.cmds.lock().unwrap();
+        let mut store = self.store.lock().unwrap();
+        let executed = Executor::new(&mut cmds, &mut store).execute();
+        if !executed.is_empty() {
+            println!("Executed {} commands", executed.len());
+        }
+        let mut reads = self.reads.lock().unwrap();
+        for ((replica_id, instance_number), value) in executed {
+            if replica_id != self.id {
+                continue;
+            }
+            if let Some(reply) = reads.remove(&instance_number) {
+                // The client may have given up already
+                let _ = reply.send(value);
+            }
+        }
+    }
+
+    // Write the new state of cmd's instance to the log. Callers hold the cmds lock,
+    // so records of an instance are in the same order as its states, and sync the
+    // returned position once they released it, before anybody learns about the state.
+    // Without the log we could go back on our promises, so we stop.
+    fn persist(&self, cmd: &Command) -> u64 {
+        match self.wal.write(cmd) {
+            Ok(written) => written,
+            Err(e) => {
+                eprintln!("Cannot write {}: {}", self.wal.path().display(), e);
+                process::exit(1);
+            }
+        }
+    }
+
+    // Returns once the log is on disk up to written. Replies that tell what we know
+    // without changing it wait for self.wal.written(), taken under the cmds lock.
+    fn sync(&self, written: u64) {
+        if let Err(e) = self.wal.sync(written) {
+            eprintln!("Cannot sync {}: {}", self.wal.path().display(), e);
+            process::exit(1);
+        }
+    }
+
+    // Replica ids in protocol messages index our instance spaces, so one that is
+    // out of range is refused before it gets there
+    fn check_replica_ids(&self, replica_id: i32, deps: &[InstanceRef]) -> Option<grpc::Error> {
+        let bad = iter::once(replica_id)
+            .chain(deps.iter().map(|dep| dep.get_replica_id()))
+            .find(|i| *i < 0 || *i >= self.replicas_num as i32)?;
+        println!("Refusing a message about replica {}", bad);
+        Some(ServiceError::InvalidArgument(format!("no replica {}", bad)).into())
+    }
+
+    // Logs the instance if a handler changed it. A reply without a change still
+    // waits for whatever it tells to be on disk.
+    fn log_change(&self, cmds: &Instances, id: (i32, i32), changed: bool) -> u64 {
+        match cmds.get(id.0, id.1) {
+            Some(cmd) if changed => self.persist(cmd),
+            _ => self.wal.written(),
+        }
+    }
+
+    fn update_conflicts(&self, cmd: &Command) {
+        (*self.conflicts.lock().unwrap()).add(cmd);
     }
 }
 
@@ -171,28 +1075,64 @@ impl EpaxosService for Epaxos {
             req.get_key(),
             req.get_value()
         );
-        self.consensus(&req);
-        // TODO when do I actually execute?
-        (*self.store.lock().unwrap()).insert(req.get_key().to_owned(), req.get_value());
-        println!("Consensus successful. Sending a commit to client.");
-        let mut r = WriteResponse::new();
-        r.set_commit(true);
-        grpc::SingleResponse::completed(r)
+        if req.get_key().is_empty() {
+            return grpc::SingleResponse::err(
+                ServiceError::InvalidArgument("empty key".to_owned()).into(),
+            );
+        }
+        let epaxos = self.clone();
+        grpc::SingleResponse::no_metadata(self.pool.spawn_fn(move || {
+            let mut cmd = epaxos.new_command();
+            cmd.set_write_req(req);
+            if epaxos.consensus(cmd, None).is_none() {
+                println!("Consensus failed. Telling the client.");
+                return Err(ServiceError::QuorumUnavailable.into());
+            }
+            println!("Consensus successful. Sending a commit to client.");
+            let mut r = WriteResponse::new();
+            r.set_commit(true);
+            Ok(r)
+        }))
     }
     fn read(
         &self,
         _m: grpc::RequestOptions,
         req: ReadRequest,
     ) -> grpc::SingleResponse<ReadResponse> {
-        // TODO: do consensus before committing
-
-        let mut r = ReadResponse::new();
-        r.set_value(*((*self.store.lock().unwrap()).get(req.get_key())).unwrap());
-        grpc::SingleResponse::completed(r)
+        println!("Received a read request with key = {}", req.get_key());
+        if req.get_key().is_empty() {
+            return grpc::SingleResponse::err(
+                ServiceError::InvalidArgument("empty key".to_owned()).into(),
+            );
+        }
+        let epaxos = self.clone();
+        grpc::SingleResponse::no_metadata(self.pool.spawn_fn(move || {
+            let mut cmd = epaxos.new_command();
+            cmd.set_read_req(req);
+            let (reply, value) = mpsc::channel();
+            let instance_number = match epaxos.consensus(cmd, Some(reply)) {
+                Some(instance_number) => instance_number,
+                None => return Err(ServiceError::QuorumUnavailable.into()),
+            };
+            // The read executes once everything it depends on has been executed
+            let value = value.recv_timeout(epaxos.recovery_timeout);
+            (*epaxos.reads.lock().unwrap()).remove(&instance_number);
+            match value {
+                Ok(value) => {
+                    let mut r = ReadResponse::new();
+                    if let Some(value) = value {
+                        r.set_value(value);
+                        r.set_found(true);
+                    }
+                    Ok(r)
+                }
+                Err(_) => Err(ServiceError::Timeout.into()),
+            }
+        }))
     }
     fn pre_accept(
         &self,
-        o: grpc::RequestOptions,
+        _o: grpc::RequestOptions,
         pre_accept_msg: PreAccept,
     ) -> grpc::SingleResponse<PreAcceptOK> {
         println!(
@@ -203,35 +1143,72 @@ impl EpaxosService for Epaxos {
             pre_accept_msg.get_write_req().get_key(),
             pre_accept_msg.get_write_req().get_value()
         );
-        let key = pre_accept_msg.get_write_req().get_key();
-        let sending_replica_id = pre_accept_msg.get_replica_id();
-        let i = pre_accept_msg.get_instance_number();
-        let interf = self.find_interference(key.to_owned());
-        let seq = cmp::max(pre_accept_msg.get_seq(), 1 + self.find_max_seq(&interf));
-        // Union interf with deps
-        let mut deps = protobuf::RepeatedField::from_vec(pre_accept_msg.get_deps().to_vec());
-        for interf_command in interf.iter() {
-            if !deps.contains(interf_command) {
-                deps.push(interf_command.clone());
-            }
-        }
-        // Add to cmd log
-        let mut cmd = Command::new();
-        cmd.set_write_req(pre_accept_msg.get_write_req().clone());
-        cmd.set_seq(seq);
-        cmd.set_deps(deps.clone());
-        cmd.set_state(State::PRE_ACCEPT);
-        (*self.cmds.lock().unwrap())[sending_replica_id as usize].insert(i as usize, cmd);
-
-        let mut r = PreAcceptOK::new();
-        r.set_replica_id(self.id);
-        r.set_write_req(pre_accept_msg.get_write_req().clone());
-        r.set_seq(seq);
-        r.set_deps(deps.clone());
-        r.set_instance_number(i);
-        return grpc::SingleResponse::completed(r);
-    }
-    fn commit(&self, o: grpc::RequestOptions, commit_msg: Commit) -> grpc::SingleResponse<Empty> {
+        if let Some(e) =
+            self.check_replica_ids(pre_accept_msg.get_replica_id(), pre_accept_msg.get_deps())
+        {
+            return grpc::SingleResponse::err(e);
+        }
+        let id = (
+            pre_accept_msg.get_replica_id(),
+            pre_accept_msg.get_instance_number(),
+        );
+        // As on the command leader, the attributes are computed and the instance
+        // indexed without letting another PreAccept in between
+        let (r, changed, written) = {
+            let mut cmds = self.cmds.lock().unwrap();
+            let mut conflicts = self.conflicts.lock().unwrap();
+            let (r, changed) =
+                replica::pre_accept(self.id, &mut cmds, &mut conflicts, &pre_accept_msg);
+            (r, changed, self.log_change(&cmds, id, changed))
+        };
+        self.sync(written);
+        if changed {
+            (*self.pending.lock().unwrap())
+                .entry(id)
+                .or_insert_with(Instant::now);
+        }
+        grpc::SingleResponse::completed(r)
+    }
+    fn accept(
+        &self,
+        _o: grpc::RequestOptions,
+        accept_msg: Accept,
+    ) -> grpc::SingleResponse<AcceptOK> {
+        println!(
+            "Replica {} received an Accept from {}\n
+            Write Key: {}, value: {}",
+            self.id,
+            accept_msg.get_replica_id(),
+            accept_msg.get_write_req().get_key(),
+            accept_msg.get_write_req().get_value()
+        );
+        if let Some(e) = self.check_replica_ids(accept_msg.get_replica_id(), accept_msg.get_deps())
+        {
+            return grpc::SingleResponse::err(e);
+        }
+        let id = (
+            accept_msg.get_replica_id(),
+            accept_msg.get_instance_number(),
+        );
+        let (r, changed, written) = {
+            let mut cmds = self.cmds.lock().unwrap();
+            let mut conflicts = self.conflicts.lock().unwrap();
+            let (r, changed) = replica::accept(self.id, &mut cmds, &mut conflicts, &accept_msg);
+            (r, changed, self.log_change(&cmds, id, changed))
+        };
+        self.sync(written);
+        if changed {
+            (*self.pending.lock().unwrap())
+                .entry(id)
+                .or_insert_with(Instant::now);
+        }
+        grpc::SingleResponse::completed(r)
+    }
+    fn commit(
+        &self,
+        _o: grpc::RequestOptions,
+        commit_msg: Commit,
+    ) -> grpc::SingleResponse<CommitOK> {
         println!(
             "Replica {} received a Commit from {}\n
             Write Key: {}, value: {}",
@@ -240,25 +1217,173 @@ impl EpaxosService for Epaxos {
             commit_msg.get_write_req().get_key(),
             commit_msg.get_write_req().get_value()
         );
-        // Update the state in the log to commit
-        ((*self.cmds.lock().unwrap())[commit_msg.get_replica_id() as usize]
-            [commit_msg.get_instance_number() as usize])
-            .set_state(State::COMMIT);
-        println!("My log is {:?}", *self.cmds.lock().unwrap());
-
-        let mut r = Empty::new();
-        return grpc::SingleResponse::completed(r);
+        if let Some(e) = self.check_replica_ids(commit_msg.get_replica_id(), commit_msg.get_deps())
+        {
+            return grpc::SingleResponse::err(e);
+        }
+        let id = (
+            commit_msg.get_replica_id(),
+            commit_msg.get_instance_number(),
+        );
+        let (r, changed, written) = {
+            let mut cmds = self.cmds.lock().unwrap();
+            let mut conflicts = self.conflicts.lock().unwrap();
+            let (r, changed) = replica::commit(self.id, &mut cmds, &mut conflicts, &commit_msg);
+            (r, changed, self.log_change(&cmds, id, changed))
+        };
+        self.sync(written);
+        if changed {
+            (*self.pending.lock().unwrap()).remove(&id);
+            // Somebody else decided our instance, delivering it is up to them
+            if id.0 == self.id {
+                self.delivered(id.1);
+            }
+            println!("My log is {:?}", *self.cmds.lock().unwrap());
+            self.execute();
+        }
+        grpc::SingleResponse::completed(r)
+    }
+    fn prepare(
+        &self,
+        _o: grpc::RequestOptions,
+        prepare_msg: Prepare,
+    ) -> grpc::SingleResponse<PrepareOK> {
+        println!(
+            "Replica {} received a Prepare for instance {}.{} with ballot {:?}",
+            self.id,
+            prepare_msg.get_replica_id(),
+            prepare_msg.get_instance_number(),
+            prepare_msg.get_ballot()
+        );
+        if let Some(e) = self.check_replica_ids(prepare_msg.get_replica_id(), &[]) {
+            return grpc::SingleResponse::err(e);
+        }
+        let id = (
+            prepare_msg.get_replica_id(),
+            prepare_msg.get_instance_number(),
+        );
+        let (r, written) = {
+            let mut cmds = self.cmds.lock().unwrap();
+            let (r, changed) = replica::prepare(self.id, &mut cmds, &prepare_msg);
+            (r, self.log_change(&cmds, id, changed))
+        };
+        self.sync(written);
+        grpc::SingleResponse::completed(r)
+    }
+    fn try_pre_accept(
+        &self,
+        _o: grpc::RequestOptions,
+        try_pre_accept_msg: TryPreAccept,
+    ) -> grpc::SingleResponse<TryPreAcceptOK> {
+        if let Some(e) = self.check_replica_ids(
+            try_pre_accept_msg.get_replica_id(),
+            try_pre_accept_msg.get_deps(),
+        ) {
+            return grpc::SingleResponse::err(e);
+        }
+        let id = (
+            try_pre_accept_msg.get_replica_id(),
+            try_pre_accept_msg.get_instance_number(),
+        );
+        println!(
+            "Replica {} received a TryPreAccept for instance {}.{}",
+            self.id, id.0, id.1
+        );
+        let (r, changed, written) = {
+            let mut cmds = self.cmds.lock().unwrap();
+            let mut conflicts = self.conflicts.lock().unwrap();
+            let (r, changed) =
+                replica::try_pre_accept(self.id, &mut cmds, &mut conflicts, &try_pre_accept_msg);
+            (r, changed, self.log_change(&cmds, id, changed))
+        };
+        self.sync(written);
+        if changed {
+            (*self.pending.lock().unwrap())
+                .entry(id)
+                .or_insert_with(Instant::now);
+        }
+        grpc::SingleResponse::completed(r)
+    }
+    fn ping(&self, _o: grpc::RequestOptions, _ping: Empty) -> grpc::SingleResponse<Empty> {
+        grpc::SingleResponse::completed(Empty::new())
+    }
+    fn peers(&self, _o: grpc::RequestOptions, _req: Empty) -> grpc::SingleResponse<PeerLatencies> {
+        let ranked = self.other_replicas();
+        let peers = self.peers.lock().unwrap();
+        let mut r = PeerLatencies::new();
+        for i in ranked {
+            let mut peer = PeerLatency::new();
+            peer.set_replica_id(i as i32);
+            peer.set_rtt_us(peers.rtt(i).map_or(0, |rtt| rtt.as_micros() as i64));
+            peer.set_connected(peers.state(i) == PeerState::Connected);
+            r.mut_peers().push(peer);
+        }
+        grpc::SingleResponse::completed(r)
     }
 }
 
 fn main() {
     let args: Vec<String> = env::args().collect();
+    if args.len() != 3 {
+        eprintln!("Usage: {} <config file> <replica id>", args[0]);
+        process::exit(1);
+    }
 
-    let id = &args[1].parse().unwrap();
-    let port = &args[2].parse().unwrap();
+    let config = match Config::load(&args[1]) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    let id: i32 = match args[2].parse() {
+        Ok(id) if config.replica(id).is_some() => id,
+        _ => {
+            eprintln!("Replica id {} is not in {}", args[2], args[1]);
+            process::exit(1);
+        }
+    };
+    let port = config.replica(id).unwrap().port;
+    let epaxos = match Epaxos::init(&id, &config) {
+        Ok(epaxos) => epaxos,
+        Err(e) => {
+            eprintln!(
+                "Cannot open the log in {}: {}",
+                config.data_dir.display(),
+                e
+            );
+            process::exit(1);
+        }
+    };
+    let recovery = epaxos.clone();
+    thread::spawn(move || loop {
+        thread::sleep(recovery.recovery_timeout);
+        recovery.recover_stalled();
+    });
+    for i in 0..config.replicas_num() {
+        if i == id as u16 {
+            continue;
+        }
+        let delivery = epaxos.clone();
+        thread::spawn(move || delivery.deliver_commits(i));
+    }
+    let probe = epaxos.clone();
+    let probe_interval = config.latency_probe_interval();
+    thread::spawn(move || loop {
+        probe.probe_latencies();
+        thread::sleep(probe_interval);
+    });
+    // Commits can arrive before the commits of their dependencies, so retry
+    // whatever could not be executed yet
+    let execution = epaxos.clone();
+    let execution_interval = config.execution_interval();
+    thread::spawn(move || loop {
+        thread::sleep(execution_interval);
+        execution.execute();
+    });
     let mut server_builder1 = grpc::ServerBuilder::new_plain();
-    server_builder1.add_service(EpaxosServiceServer::new_service_def(Epaxos::init(&id)));
-    server_builder1.http.set_port(*port);
+    server_builder1.add_service(EpaxosServiceServer::new_service_def(epaxos));
+    server_builder1.http.set_port(port);
     let server1 = server_builder1.build().expect("build");
     println!("server 1 started on addr {}", server1.local_addr());
 
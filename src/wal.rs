@@ -0,0 +1,323 @@
+// Write-ahead log of instance state. Before a replica answers a protocol message
+// or acts on its own instances, the new state of the instance is appended here
+// and fsync'd, so that a restarted replica remembers every promise and vote.
+//
+// A record is the whole Command, protobuf encoded, after a u32 little endian
+// length. The last record of an instance is its current state. A crash while
+// appending can leave a torn record at the end, which replay drops.
+//
+// Writing a record and waiting for it to be on disk are separate steps, so that
+// callers can write while holding their own locks and wait without them. One
+// fsync covers every record written before it, so concurrent waiters share it.
+//
+// Replay compacts the log down to the last record of each instance. Next to the
+// log, the replica keeps the instance number up to which every other replica
+// acknowledged the Commits of its own instances, so that it does not send those
+// again after a restart.
+
+use crate::epaxos::Command;
+use protobuf::Message;
+use std::{
+    collections::HashMap,
+    fs::{self, File, OpenOptions},
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    sync::{Condvar, Mutex},
+};
+
+pub struct Wal {
+    path: PathBuf,
+    file: Mutex<File>,
+    // The same file, so that an fsync does not hold up appends
+    sync_file: File,
+    progress: Mutex<Progress>,
+    synced: Condvar,
+    delivered_path: PathBuf,
+    delivered: Mutex<i32>,
+}
+
+#[derive(Default)]
+struct Progress {
+    // Records written since the log was opened, and how many of them are on disk
+    written: u64,
+    synced: u64,
+    syncing: bool,
+}
+
+impl Wal {
+    // Opens the log of replica id in dir, creating both if needed
+    pub fn open(dir: &Path, id: i32) -> io::Result<Wal> {
+        fs::create_dir_all(dir)?;
+        let path = dir.join(format!("replica-{}.wal", id));
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let sync_file = file.try_clone()?;
+        let delivered_path = dir.join(format!("replica-{}.delivered", id));
+        let delivered = match fs::read_to_string(&delivered_path) {
+            Ok(s) => s.trim().parse().unwrap_or_else(|_| {
+                println!("Ignoring {}", delivered_path.display());
+                -1
+            }),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => -1,
+            Err(e) => return Err(e),
+        };
+        Ok(Wal {
+            path,
+            file: Mutex::new(file),
+            sync_file,
+            progress: Default::default(),
+            synced: Condvar::new(),
+            delivered_path,
+            delivered: Mutex::new(delivered),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // The last complete record of every instance in the log. When the log holds
+    // older records, or a torn one at the end, it is rewritten with just these.
+    pub fn replay(&mut self) -> io::Result<Vec<Command>> {
+        let mut bytes = Vec::new();
+        File::open(&self.path)?.read_to_end(&mut bytes)?;
+        let mut cmds = Vec::new();
+        let mut offset = 0;
+        while offset + 4 <= bytes.len() {
+            let mut len = [0; 4];
+            len.copy_from_slice(&bytes[offset..offset + 4]);
+            let end = offset + 4 + u32::from_le_bytes(len) as usize;
+            if end > bytes.len() {
+                break;
+            }
+            let mut cmd = Command::new();
+            if cmd.merge_from_bytes(&bytes[offset + 4..end]).is_err() {
+                break;
+            }
+            cmds.push(cmd);
+            offset = end;
+        }
+        if offset < bytes.len() {
+            println!(
+                "Dropping {} bytes of a torn record at the end of {}",
+                bytes.len() - offset,
+                self.path.display()
+            );
+        }
+        let records = cmds.len();
+        let mut index = HashMap::new();
+        let mut latest: Vec<Command> = Vec::new();
+        for cmd in cmds {
+            let id = (cmd.get_replica_id(), cmd.get_instance_number());
+            match index.get(&id) {
+                Some(&i) => latest[i] = cmd,
+                None => {
+                    index.insert(id, latest.len());
+                    latest.push(cmd);
+                }
+            }
+        }
+        if latest.len() < records || offset < bytes.len() {
+            self.rewrite(&latest)?;
+            println!(
+                "Compacted {} from {} to {} records",
+                self.path.display(),
+                records,
+                latest.len()
+            );
+        }
+        Ok(latest)
+    }
+
+    // Replaces the log with cmds. The new log is complete on disk before it takes
+    // the place of the old one, so a crash leaves one or the other.
+    fn rewrite(&mut self, cmds: &[Command]) -> io::Result<()> {
+        let tmp_path = self.path.with_extension("wal.tmp");
+        let mut tmp = File::create(&tmp_path)?;
+        for cmd in cmds {
+            tmp.write_all(&record(cmd)?)?;
+        }
+        tmp.sync_all()?;
+        fs::rename(&tmp_path, &self.path)?;
+        if let Some(dir) = self.path.parent() {
+            File::open(dir)?.sync_all()?;
+        }
+        let file = OpenOptions::new().append(true).open(&self.path)?;
+        self.sync_file = file.try_clone()?;
+        self.file = Mutex::new(file);
+        Ok(())
+    }
+
+    // Appends the record without waiting for the disk. Returns its position, to
+    // pass to sync.
+    pub fn write(&self, cmd: &Command) -> io::Result<u64> {
+        let record = record(cmd)?;
+        let mut file = self.file.lock().unwrap();
+        file.write_all(&record)?;
+        let mut progress = self.progress.lock().unwrap();
+        progress.written += 1;
+        Ok(progress.written)
+    }
+
+    // The position of the last record written
+    pub fn written(&self) -> u64 {
+        self.progress.lock().unwrap().written
+    }
+
+    // Returns once every record up to position is on disk
+    pub fn sync(&self, position: u64) -> io::Result<()> {
+        let mut progress = self.progress.lock().unwrap();
+        loop {
+            if progress.synced >= position {
+                return Ok(());
+            }
+            if progress.syncing {
+                progress = self.synced.wait(progress).unwrap();
+                continue;
+            }
+            // Everything written so far goes to disk with this fsync
+            let target = progress.written;
+            progress.syncing = true;
+            drop(progress);
+            let result = self.sync_file.sync_data();
+            progress = self.progress.lock().unwrap();
+            progress.syncing = false;
+            if result.is_ok() {
+                progress.synced = target;
+            }
+            self.synced.notify_all();
+            result?;
+        }
+    }
+
+    // Every Commit of our own instances up to this one was acknowledged by all
+    pub fn delivered(&self) -> i32 {
+        *self.delivered.lock().unwrap()
+    }
+
+    // Records that the Commits up to through were acknowledged. Losing this only
+    // makes a restarted replica send some Commits again.
+    pub fn set_delivered(&self, through: i32) -> io::Result<()> {
+        let mut delivered = self.delivered.lock().unwrap();
+        if through <= *delivered {
+            return Ok(());
+        }
+        let tmp_path = self.delivered_path.with_extension("delivered.tmp");
+        let mut tmp = File::create(&tmp_path)?;
+        tmp.write_all(format!("{}\n", through).as_bytes())?;
+        tmp.sync_data()?;
+        fs::rename(&tmp_path, &self.delivered_path)?;
+        *delivered = through;
+        Ok(())
+    }
+}
+
+// A record is the encoded command after its length
+fn record(cmd: &Command) -> io::Result<Vec<u8>> {
+    let bytes = cmd
+        .write_to_bytes()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let mut record = Vec::with_capacity(4 + bytes.len());
+    record.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    record.extend_from_slice(&bytes);
+    Ok(record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::epaxos::State;
+    use std::env;
+
+    // A fresh directory for one test's log
+    fn temp_dir(test: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("epaxos-wal-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn instance(instance_number: i32, state: State) -> Command {
+        let mut cmd = Command::new();
+        cmd.set_replica_id(1);
+        cmd.set_instance_number(instance_number);
+        cmd.set_state(state);
+        cmd
+    }
+
+    fn write_all(wal: &Wal, cmds: &[Command]) {
+        for cmd in cmds {
+            let written = wal.write(cmd).unwrap();
+            wal.sync(written).unwrap();
+        }
+    }
+
+    #[test]
+    fn a_torn_last_record_is_dropped() {
+        let dir = temp_dir("torn");
+        let wal = Wal::open(&dir, 0).unwrap();
+        write_all(
+            &wal,
+            &[instance(0, State::COMMIT), instance(1, State::PRE_ACCEPT)],
+        );
+        let len = fs::metadata(wal.path()).unwrap().len();
+        OpenOptions::new()
+            .write(true)
+            .open(wal.path())
+            .unwrap()
+            .set_len(len - 1)
+            .unwrap();
+        drop(wal);
+
+        let mut wal = Wal::open(&dir, 0).unwrap();
+        assert_eq!(wal.replay().unwrap(), vec![instance(0, State::COMMIT)]);
+        // Records written after the cut are found again
+        write_all(&wal, &[instance(2, State::ACCEPTED)]);
+        let mut wal = Wal::open(&dir, 0).unwrap();
+        assert_eq!(
+            wal.replay().unwrap(),
+            vec![instance(0, State::COMMIT), instance(2, State::ACCEPTED)]
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn replay_keeps_the_last_record_of_each_instance() {
+        let dir = temp_dir("compact");
+        let wal = Wal::open(&dir, 0).unwrap();
+        write_all(
+            &wal,
+            &[
+                instance(0, State::PRE_ACCEPT),
+                instance(1, State::PRE_ACCEPT),
+                instance(0, State::ACCEPTED),
+                instance(0, State::COMMIT),
+            ],
+        );
+        let len = fs::metadata(wal.path()).unwrap().len();
+        drop(wal);
+
+        let expected = vec![instance(0, State::COMMIT), instance(1, State::PRE_ACCEPT)];
+        let mut wal = Wal::open(&dir, 0).unwrap();
+        assert_eq!(wal.replay().unwrap(), expected);
+        assert!(fs::metadata(wal.path()).unwrap().len() < len);
+        let mut wal = Wal::open(&dir, 0).unwrap();
+        assert_eq!(wal.replay().unwrap(), expected);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn delivered_survives_a_restart() {
+        let dir = temp_dir("delivered");
+        let wal = Wal::open(&dir, 0).unwrap();
+        assert_eq!(wal.delivered(), -1);
+        wal.set_delivered(3).unwrap();
+        // The mark never goes back
+        wal.set_delivered(2).unwrap();
+        assert_eq!(wal.delivered(), 3);
+        drop(wal);
+
+        assert_eq!(Wal::open(&dir, 0).unwrap().delivered(), 3);
+        // Every replica has its own
+        assert_eq!(Wal::open(&dir, 1).unwrap().delivered(), -1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}